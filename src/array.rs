@@ -1,7 +1,24 @@
-use std::alloc::{Layout, LayoutError};
+use std::alloc::Layout;
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr::NonNull;
+use std::slice::SliceIndex;
 
-use super::ArrayError;
+use super::{ArrayError, Zeroable};
+use super::backing::{Backing, ReleaseGuard};
 use super::{Iter, IterMut, IntoIter};
+use super::{ArrayView, ArrayViewD, ArrayViewMut};
+use super::array_views::Strided;
+use super::arrayd_views::StridedD;
+
+
+/// Whether the unchecked accessors assert the index bounds:
+/// in debug builds and with the `checked-unsafe` feature.
+const CHECK_UNSAFE: bool = cfg!(any(debug_assertions, feature = "checked-unsafe"));
 
 
 /// Base `struct` of the crate.
@@ -19,51 +36,184 @@ use super::{Iter, IterMut, IntoIter};
 /// arr[2] == 3;
 /// ```
 ///
+/// # Thread safety
+///
+/// Like [`Vec<T>`](std::vec::Vec), the array is `Send` if `T: Send`
+/// and `Sync` if `T: Sync`:
+///
+/// ```
+/// use std::sync::Arc;
+/// use runtime_sized_array::Array;
+///
+/// let table: Arc<Array<i32>> = Arc::new(vec![1, 2, 3].into());
+/// let worker = {
+///     let table = table.clone();
+///     std::thread::spawn(move || table[1] * 2)
+/// };
+/// assert_eq!(worker.join().unwrap(), 4);
+/// ```
+///
+/// An array of `!Send` elements cannot be moved to another thread:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use runtime_sized_array::Array;
+///
+/// let arr: Array<Rc<i32>> = vec![Rc::new(1)].into();
+/// std::thread::spawn(move || drop(arr));
+/// ```
+///
+/// And an array of `!Sync` elements cannot be shared between threads:
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use std::sync::Arc;
+/// use runtime_sized_array::Array;
+///
+/// let arr: Arc<Array<Cell<i32>>> = Arc::new(vec![Cell::new(1)].into());
+/// std::thread::spawn(move || arr[0].set(2));
+/// ```
+///
 pub struct Array<T> {
-    pub(in super) pointer : *mut T,
-    size : usize
+    pub(in super) pointer : NonNull<T>,
+    size : usize,
+    backing : Backing,
+    // the array owns values of type `T`
+    marker : PhantomData<T>
 }
 
+
+// the array owns its elements like `Vec<T>` does
+unsafe impl<T: Send> Send for Array<T> {}
+
+unsafe impl<T: Sync> Sync for Array<T> {}
+
+
 impl<T> Array<T> {
 
-    /// Creates an `Array` with the given size or returns `ArrayError`
-    /// if any of the following cases happened:
+    /// Creates an `Array` with the given size, all elements of which are zeroed,
+    /// or returns `ArrayError` if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// Available only for [`Zeroable`] types. For other types use
+    /// [`new_uninit`](Array::new_uninit).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr: Array<i32> = Array::new(3).unwrap();
+    /// assert_eq!(&*arr, &[0, 0, 0]);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn new(size: usize) -> Result<Array<T>, ArrayError>
+        where T: Zeroable
+    {
+        // all-zero bit pattern is a valid `T`
+        Array::new_zeroed(size).map(|arr| unsafe { arr.assume_init() })
+    }
+
+    /// Creates an `Array` with the given size and uninitialized contents
+    /// or returns `ArrayError` if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// Elements are written with [`write`](Array::write), after that the array
+    /// is turned into `Array<T>` by [`assume_init`](Array::assume_init).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr = Array::<String>::new_uninit(2).unwrap();
+    /// arr.write(0, "a".to_string());
+    /// arr.write(1, "b".to_string());
+    /// let arr: Array<String> = unsafe { arr.assume_init() };
+    /// assert_eq!(arr[1], "b");
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn new_uninit(size: usize) -> Result<Array<MaybeUninit<T>>, ArrayError> {
+        Array::allocate(size, false)
+    }
+
+    /// Creates an `Array` with the given size, memory of which is filled with `0` bytes,
+    /// or returns `ArrayError` if any of the following cases happened:
     /// * failed creating a [`layout`] with the following size,
     /// * failed [allocating] memory for the array.
     ///
+    /// Whether the zeroed memory is a valid `T` depends on `T`, so the elements
+    /// are still wrapped into [`MaybeUninit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let arr = Array::<u32>::new_zeroed(2).unwrap();
+    /// let arr: Array<u32> = unsafe { arr.assume_init() };
+    /// assert_eq!(arr[1], 0);
+    /// ```
+    ///
     /// [allocating]: std::alloc
     /// [`layout`]: std::alloc::Layout
     #[inline]
-    pub fn new(size: usize) -> Result<Array<T>, ArrayError> {
+    pub fn new_zeroed(size: usize) -> Result<Array<MaybeUninit<T>>, ArrayError> {
+        Array::allocate(size, true)
+    }
+
+    fn allocate(size: usize, zeroed: bool) -> Result<Array<MaybeUninit<T>>, ArrayError> {
         unsafe {
-            let layout = std::alloc::Layout::array::<T>(size)?;
-            let ptr = std::alloc::alloc(layout) as *mut T;
-            if ptr.is_null() {
-                Err(ArrayError("allocation returned null pointer".to_string()))
+            let layout = Layout::array::<T>(size)
+                .map_err(|err| ArrayError::layout_overflow::<T>(size, err))?;
+            let ptr = if layout.size() == 0 {
+                // zero-sized allocations are not allowed,
+                // for empty arrays and zero-sized types any aligned pointer is enough
+                NonNull::<MaybeUninit<T>>::dangling().as_ptr()
+            } else if zeroed {
+                std::alloc::alloc_zeroed(layout) as *mut MaybeUninit<T>
             } else {
-                Ok(Self { pointer: ptr, size })
+                std::alloc::alloc(layout) as *mut MaybeUninit<T>
+            };
+            match NonNull::new(ptr) {
+                None => Err(ArrayError::AllocFailed { layout }),
+                Some(ptr) => Ok(Array::from_raw_parts(ptr, size, Backing::Owned))
             }
         }
     }
 
-
     /// Creates an `Array` from the given raw pointer with the given size
     ///
     /// # Safety
     ///
-    /// The caller must ensure that the memory the `ptr` refers can be deallocated
-    /// by another structure. Also dropping the array, returned by this function
-    /// will immediately cause deallocating of the memory. All this may cause undefined
-    /// behaviour.
+    /// The array takes the ownership of the elements and of the memory: dropping it
+    /// drops all `size` elements and deallocates the memory by the global allocator
+    /// with [`Layout::array::<T>(size)`](std::alloc::Layout::array).
+    /// So the caller must ensure that `ptr` points to `size` initialized elements,
+    /// which were allocated exactly this way (like the memory of a `Box<[T]>`),
+    /// and that nothing else will free them. Otherwise it is undefined behaviour.
+    ///
+    /// For memory of other origin use [`from_vec_pointer`](Array::from_vec_pointer),
+    /// [`from_borrowed_pointer`](Array::from_borrowed_pointer) or
+    /// [`from_foreign_pointer`](Array::from_foreign_pointer).
     ///
-    /// What's more, the function does not check is the pointer is null.
+    /// What's more, the function does not check is the pointer is null,
+    /// and a null pointer is undefined behaviour even for an empty array.
     ///
     ///
     /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
-    /// let mut vec = vec![1,2,3];
+    /// // `vec!` allocates exactly `3` elements, the memory is freed by the array
+    /// let mut vec = std::mem::ManuallyDrop::new(vec![1,2,3]);
     /// let ptr = vec.as_mut_ptr();
     /// let size = vec.len();
     /// unsafe {
@@ -72,7 +222,107 @@ impl<T> Array<T> {
     /// ```
     #[inline]
     pub unsafe fn from_pointer(ptr: *mut T, size: usize) -> Self {
-        Self { pointer : ptr, size }
+        Array::from_raw_parts(NonNull::new_unchecked(ptr), size, Backing::Owned)
+    }
+
+    /// Creates an `Array` from the raw parts of a [`Vec`](std::vec::Vec):
+    /// pointer, length (the size of the array) and capacity.
+    ///
+    /// # Safety
+    ///
+    /// The array takes the ownership of the elements and of the memory: dropping it
+    /// drops all `size` elements and frees the memory the way the vector would do.
+    /// So the caller must ensure that the arguments satisfy all the requirements of
+    /// [`Vec::from_raw_parts`](std::vec::Vec::from_raw_parts) (including non-null `ptr`)
+    /// and that nothing else will free the memory. Otherwise it is undefined behaviour.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut vec = std::mem::ManuallyDrop::new(Vec::with_capacity(10));
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    /// let arr: Array<i32> = unsafe {
+    ///     Array::from_vec_pointer(vec.as_mut_ptr(), vec.len(), vec.capacity())
+    /// };
+    /// assert_eq!(arr.size(), 3);
+    /// ```
+    #[inline]
+    pub unsafe fn from_vec_pointer(ptr: *mut T, size: usize, capacity: usize) -> Self {
+        Array::from_raw_parts(NonNull::new_unchecked(ptr), size, Backing::Vec { capacity })
+    }
+
+    /// Creates an `Array` from the given raw pointer with the given size,
+    /// memory of which is never freed by the array.
+    ///
+    /// # Safety
+    ///
+    /// The array takes the ownership of the elements, but not of the memory: dropping it
+    /// drops all `size` elements and does nothing else.
+    /// So the caller must ensure that `ptr` is non-null and points to `size` initialized
+    /// elements, that the memory outlives the array and that the elements will not be
+    /// dropped by anything else. Otherwise it is undefined behaviour.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut buffer = [1, 2, 3];
+    /// {
+    ///     let mut arr: Array<i32> = unsafe {
+    ///         Array::from_borrowed_pointer(buffer.as_mut_ptr(), buffer.len())
+    ///     };
+    ///     arr[0] = 5;
+    /// }
+    /// assert_eq!(buffer, [5, 2, 3]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_borrowed_pointer(ptr: *mut T, size: usize) -> Self {
+        Array::from_raw_parts(NonNull::new_unchecked(ptr), size, Backing::Borrowed)
+    }
+
+    /// Creates an `Array` from the given raw pointer with the given size,
+    /// memory of which is freed by the given `dealloc` function.
+    ///
+    /// When the array is dropped, it drops all `size` elements and then calls
+    /// `dealloc(ptr, size)`. This is the way to adopt buffers, allocated by
+    /// foreign code (for example by `malloc`).
+    ///
+    /// # Safety
+    ///
+    /// The array takes the ownership of the elements: the caller must ensure that
+    /// `ptr` is non-null and points to `size` initialized elements, that the memory
+    /// is valid until `dealloc` is called and that nothing else will free it or
+    /// drop the elements. Otherwise it is undefined behaviour.
+    ///
+    /// `dealloc` is required to be `Send` and `Sync`, because the array may be
+    /// dropped by another thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// extern "C" {
+    ///     fn malloc(size: usize) -> *mut u8;
+    ///     fn free(ptr: *mut u8);
+    /// }
+    ///
+    /// unsafe {
+    ///     let ptr = malloc(3 * std::mem::size_of::<i32>()) as *mut i32;
+    ///     for i in 0..3 {
+    ///         ptr.add(i).write(i as i32);
+    ///     }
+    ///     let arr: Array<i32> = Array::from_foreign_pointer(ptr, 3, |ptr, _| free(ptr as *mut u8));
+    ///     assert_eq!(arr[2], 2);
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn from_foreign_pointer<D>(ptr: *mut T, size: usize, dealloc: D) -> Self
+        where D: FnOnce(*mut T, usize) + Send + Sync + 'static
+    {
+        let dealloc = Box::new(move |ptr: *mut u8, size| dealloc(ptr as *mut T, size));
+        Array::from_raw_parts(NonNull::new_unchecked(ptr), size, Backing::Foreign(dealloc))
     }
 
 
@@ -104,9 +354,15 @@ impl<T> Array<T> {
     /// This method does not check the index bounds, so it's more efficient,
     /// but can produce undefined behaviour
     ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if the index is greater than the size of the array
+    /// (a pointer one past the last element is allowed).
+    ///
     #[inline]
+    #[track_caller]
     pub unsafe fn get_ptr(&self, index: usize) -> *const T {
-        self.pointer.add(index)
+        self.check_offset_unchecked(index);
+        self.pointer.as_ptr().add(index)
     }
 
     /// Returns a mutable raw pointer at an element by the given index
@@ -129,9 +385,15 @@ impl<T> Array<T> {
     /// This method does not check the index bounds, so it's more efficient,
     /// but can produce undefined behaviour
     ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if the index is greater than the size of the array
+    /// (a pointer one past the last element is allowed).
+    ///
     #[inline]
+    #[track_caller]
     pub unsafe fn get_mut_ptr(&self, index: usize) -> *mut T {
-        self.pointer.add(index)
+        self.check_offset_unchecked(index);
+        self.pointer.as_ptr().add(index)
     }
 
     /// Returns immutable reference at an element
@@ -175,10 +437,15 @@ impl<T> Array<T> {
     /// This method does not check the index bounds, so it's more efficient,
     /// but can produce undefined behaviour
     ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if the index is out of bounds.
+    ///
     /// If you want safe immutable access, use [`try_get`](Array::try_get).
     #[inline]
+    #[track_caller]
     pub unsafe fn get(&self, index: usize) -> &T {
-        &(*(self.pointer.add(index)))
+        self.check_index_unchecked(index);
+        &(*(self.pointer.as_ptr().add(index)))
     }
 
     /// Returns mutable reference at an element
@@ -222,10 +489,15 @@ impl<T> Array<T> {
     /// This method does not check the index bounds, so it's more efficient,
     /// but can produce undefined behaviour
     ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if the index is out of bounds.
+    ///
     /// If you want safe mutable access, use [`try_get_mut`](Array::try_get_mut).
     #[inline]
+    #[track_caller]
     pub unsafe fn get_mut(&mut self, index: usize) -> &mut T {
-        &mut (*(self.pointer.add(index)))
+        self.check_index_unchecked(index);
+        &mut (*(self.pointer.as_ptr().add(index)))
     }
 
     /// Alternative way to safely change elements of the array.
@@ -241,12 +513,16 @@ impl<T> Array<T> {
     /// assert_eq!(arr.try_set(1, 5), Some(()));
     /// assert_eq!(arr.try_set(10, 4), None);
     /// ```
+    ///
+    /// Use [`set_checked`](Array::set_checked) to get the old value
+    /// or the rejected one back.
     #[inline]
     pub fn try_set(&mut self, index: usize, value: T) -> Option<()> {
         if self.size <= index {
             None
         } else {
-            unsafe { Some(self.set(index, value)) }
+            unsafe { self.set(index, value) }
+            Some(())
         }
     }
 
@@ -272,150 +548,253 @@ impl<T> Array<T> {
     /// This method does not check the index bounds, so it's more efficient,
     /// but can produce undefined behaviour
     ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if the index is out of bounds.
+    ///
     /// If you look for safe version, use [`try_set`](Array::try_set).
     #[inline]
+    #[track_caller]
     pub unsafe fn set(&mut self, index: usize, value: T) {
-        *(self.pointer.add(index)) = value
+        self.check_index_unchecked(index);
+        *(self.pointer.as_ptr().add(index)) = value
     }
 
-    /// Returns an iterator over the array.
-    ///
-    /// The iterator yields all items from start to end.
+    /// Returns immutable reference at an element
+    /// or [`IndexOutOfBounds`](ArrayError::IndexOutOfBounds) with the index and the size of the array.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
-    /// use runtime_sized_array::Array;
-    ///
-    /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// let mut iterator = arr.iter();
+    /// use runtime_sized_array::{Array, ArrayError};
     ///
-    /// assert_eq!(iterator.next(), Some(&1));
-    /// assert_eq!(iterator.next(), Some(&2));
-    /// assert_eq!(iterator.next(), Some(&4));
-    /// assert_eq!(iterator.next(), None);
+    /// let arr: Array<i32> = vec![1,2,4].into();
+    /// assert_eq!(arr.get_checked(1), Ok(&2));
+    /// assert_eq!(arr.get_checked(10), Err(ArrayError::IndexOutOfBounds { index: 10, len: 3 }));
     /// ```
     #[inline]
-    pub fn iter(&self) -> Iter<T> {
-        Iter::new(self)
+    pub fn get_checked(&self, index: usize) -> Result<&T, ArrayError> {
+        self.check_index(index)?;
+        unsafe { Ok(self.get(index)) }
     }
 
-    /// Returns an iterator that allows modifying each value.
-    ///
-    /// The iterator yields all items from start to end.
+    /// Returns mutable reference at an element
+    /// or [`IndexOutOfBounds`](ArrayError::IndexOutOfBounds) with the index and the size of the array.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
-    /// use runtime_sized_array::Array;
+    /// use runtime_sized_array::{Array, ArrayError};
     ///
     /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// for elem in arr.iter_mut() {
-    ///     *elem += 2;
-    /// }
-    /// assert_eq!(arr[0], 3);
+    /// *arr.get_checked_mut(1).unwrap() = 5;
+    /// assert_eq!(arr[1], 5);
+    /// assert!(arr.get_checked_mut(10).is_err());
     /// ```
     #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        IterMut::new(self)
+    pub fn get_checked_mut(&mut self, index: usize) -> Result<&mut T, ArrayError> {
+        self.check_index(index)?;
+        unsafe { Ok(self.get_mut(index)) }
     }
 
-    /// Converts the array into a [`Vec`](std::vec::Vec)
+    /// Puts the `value` at the given index and returns the old value.
     ///
-    /// The array cannot be used after calling this.
+    /// If the index is out of bounds, returns
+    /// [`IndexOutOfBounds`](ArrayError::IndexOutOfBounds) together with the rejected `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, ArrayError};
+    ///
+    /// let mut arr: Array<String> = vec!["a".to_string()].into();
+    /// assert_eq!(arr.set_checked(0, "b".to_string()), Ok("a".to_string()));
+    ///
+    /// let (err, value) = arr.set_checked(1, "c".to_string()).unwrap_err();
+    /// assert_eq!(err, ArrayError::IndexOutOfBounds { index: 1, len: 1 });
+    /// assert_eq!(value, "c");
+    /// ```
     #[inline]
-    pub fn into_vec(self) -> Vec<T> {
-        unsafe{
-            Vec::from_raw_parts(self.pointer, self.size, self.size)
+    pub fn set_checked(&mut self, index: usize, value: T) -> Result<T, (ArrayError, T)> {
+        match self.check_index(index) {
+            Ok(()) => Ok(std::mem::replace(unsafe { self.get_mut(index) }, value)),
+            Err(err) => Err((err, value)),
         }
     }
 
-    /// Returns immutable raw pointer to the memory, allocated by the array.
+    /// Puts the `value` at the given index and returns the old value.
     ///
-    /// The caller must ensure that the array outlives the pointer this
-    /// function returns, or else it will end up pointing to garbage.
+    /// # Panics
     ///
-    /// The caller must also ensure that the memory the pointer (non-transitively) points to
-    /// is never written to (except inside an `UnsafeCell`) using this pointer or any pointer
-    /// derived from it. If you need to mutate the contents of the array, use [`as_mut_ptr`].
+    /// if the index is out of bounds. Use [`set_checked`](Array::set_checked)
+    /// to handle this case.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
     ///
     /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// let ptr = arr.as_ptr();
-    /// unsafe {
-    ///     for i in 0..arr.size() {
-    ///         assert_eq!(arr[i], *ptr.add(i));
-    ///     }
-    /// }
+    /// assert_eq!(arr.replace(1, 5), 2);
+    /// assert_eq!(arr, [1, 5, 4]);
     /// ```
-    ///
-    /// [`as_mut_ptr`]: Array::as_mut_ptr
     #[inline]
-    pub fn as_ptr(&self) -> *const T {
-        self.pointer.as_const()
+    #[track_caller]
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        std::mem::replace(&mut self[index], value)
     }
 
-    /// Returns mutable raw pointer to the memory, allocated by the array.
+    /// Swaps the elements at the indices `a` and `b`.
     ///
-    /// The caller must ensure that the array outlives the pointer this
-    /// function returns, or else it will end up pointing to garbage.
+    /// # Panics
     ///
-    /// # Examples
+    /// if `a` or `b` is out of bounds.
+    ///
+    /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
     ///
     /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// let ptr = arr.as_mut_ptr();
-    /// unsafe {
-    ///     for i in 0..arr.size() {
-    ///         *ptr.add(i) += 2;
-    ///     }
-    /// }
+    /// arr.swap_values(0, 2);
+    /// assert_eq!(arr, [4, 2, 1]);
     /// ```
-    ///
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut T {
-        self.pointer
+    #[track_caller]
+    pub fn swap_values(&mut self, a: usize, b: usize) {
+        self.swap(a, b)
     }
 
-}
-
+    /// Returns mutable references to the elements at the `N` given indices at once.
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * an index is out of bounds ([`IndexOutOfBounds`](ArrayError::IndexOutOfBounds)),
+    /// * an index is given more than once ([`DuplicateIndex`](ArrayError::DuplicateIndex)),
+    ///   because two mutable references to one element are not allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, ArrayError};
+    ///
+    /// let mut arr: Array<i32> = vec![1, 2, 4].into();
+    /// let [a, b] = arr.get_many_mut([0, 2]).unwrap();
+    /// std::mem::swap(a, b);
+    /// assert_eq!(arr, [4, 2, 1]);
+    ///
+    /// assert_eq!(arr.get_many_mut([1, 1]).err(), Some(ArrayError::DuplicateIndex { index: 1 }));
+    /// ```
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N])
+        -> Result<[&mut T; N], ArrayError>
+    {
+        self.check_many(&indices)?;
+        unsafe { Ok(self.get_many_unchecked_mut(indices)) }
+    }
 
-impl<'a, T> IntoIterator for &'a Array<T> {
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    /// Returns mutable references to the elements at the `N` given indices at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr: Array<i32> = vec![1, 2, 4].into();
+    /// let [a, b, c] = unsafe { arr.get_many_unchecked_mut([2, 0, 1]) };
+    /// *a += *b + *c;
+    /// assert_eq!(arr, [1, 2, 7]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// This method checks neither the index bounds nor the uniqueness of the indices,
+    /// so it's more efficient, but out-of-bounds or repeated indices produce undefined behaviour.
+    ///
+    /// In debug builds or with the `checked-unsafe` feature it panics instead,
+    /// if an index is out of bounds or repeated.
+    ///
+    /// If you look for safe version, use [`get_many_mut`](Array::get_many_mut).
+    #[inline]
+    #[track_caller]
+    pub unsafe fn get_many_unchecked_mut<const N: usize>(&mut self, indices: [usize; N])
+        -> [&mut T; N]
+    {
+        if CHECK_UNSAFE {
+            if let Err(err) = self.check_many(&indices) {
+                panic!("{err}");
+            }
+        }
+        let ptr = self.pointer.as_ptr();
+        indices.map(|index| &mut *ptr.add(index))
+    }
 
-    /// Returns an iterator over the array.
+    /// Divides the array into two mutable views at the index `mid`:
+    /// the first one contains the elements `[0, mid)`, the second one `[mid, size)`.
     ///
-    /// The iterator yields all items from start to end.
+    /// # Panics
     ///
-    /// # Examples
+    /// if `mid > size`.
+    ///
+    /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
     ///
-    /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// for item in &arr {
-    ///     println!("{item}");
+    /// let mut arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    /// let (mut left, right) = arr.split_at_mut_array(2);
+    /// for (l, r) in left.iter_mut().zip(right) {
+    ///     *l += *r;
     /// }
+    /// assert_eq!(arr, [4, 6, 3, 4, 5]);
     /// ```
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    #[track_caller]
+    pub fn split_at_mut_array(&mut self, mid: usize) -> (ArrayViewMut<'_, T>, ArrayViewMut<'_, T>) {
+        self.view_mut().split_at(mid)
     }
-}
 
+    /// Returns [`IndexOutOfBounds`](ArrayError::IndexOutOfBounds), if the index is out of bounds.
+    #[inline]
+    fn check_index(&self, index: usize) -> Result<(), ArrayError> {
+        if index < self.size {
+            Ok(())
+        } else {
+            Err(ArrayError::IndexOutOfBounds { index, len: self.size })
+        }
+    }
 
-impl<'a, T> IntoIterator for &'a mut Array<T> {
-    type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
+    /// Checks, that all the indices are in bounds and distinct.
+    fn check_many(&self, indices: &[usize]) -> Result<(), ArrayError> {
+        for (i, &index) in indices.iter().enumerate() {
+            self.check_index(index)?;
+            // there are few indices, so the quadratic search is the fastest
+            if indices[..i].contains(&index) {
+                return Err(ArrayError::DuplicateIndex { index });
+            }
+        }
+        Ok(())
+    }
 
-    /// Returns an iterator that allows modifying each value.
+    /// Asserts, that the index of an unchecked access is in bounds,
+    /// if [`CHECK_UNSAFE`] is on. Otherwise compiles to nothing.
+    #[inline(always)]
+    #[track_caller]
+    fn check_index_unchecked(&self, index: usize) {
+        if CHECK_UNSAFE && index >= self.size {
+            panic!("unchecked access out of bounds: the len is {} but the index is {index}", self.size);
+        }
+    }
+
+    /// Asserts, that the pointer offset is in bounds
+    /// (pointing one past the last element is allowed), if [`CHECK_UNSAFE`] is on.
+    /// Otherwise compiles to nothing.
+    #[inline(always)]
+    #[track_caller]
+    fn check_offset_unchecked(&self, index: usize) {
+        if CHECK_UNSAFE && index > self.size {
+            panic!("unchecked pointer out of bounds: the len is {} but the offset is {index}", self.size);
+        }
+    }
+
+    /// Returns an iterator over the array.
     ///
     /// The iterator yields all items from start to end.
     ///
@@ -425,199 +804,972 @@ impl<'a, T> IntoIterator for &'a mut Array<T> {
     /// use runtime_sized_array::Array;
     ///
     /// let mut arr: Array<i32> = vec![1,2,4].into();
-    /// for elem in &mut arr {
-    ///     *elem += 2;
-    /// }
-    /// assert_eq!(arr[0], 3);
+    /// let mut iterator = arr.iter();
+    ///
+    /// assert_eq!(iterator.next(), Some(&1));
+    /// assert_eq!(iterator.next(), Some(&2));
+    /// assert_eq!(iterator.next(), Some(&4));
+    /// assert_eq!(iterator.next(), None);
     /// ```
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self)
     }
-}
 
+    /// Returns an iterator that allows modifying each value.
+    ///
+    /// The iterator yields all items from start to end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr: Array<i32> = vec![1,2,4].into();
+    /// for elem in arr.iter_mut() {
+    ///     *elem += 2;
+    /// }
+    /// assert_eq!(arr[0], 3);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self)
+    }
 
-impl<T> IntoIterator for Array<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
+    /// Returns an immutable view of all the elements of the array.
+    ///
+    /// Unlike a slice, the view can be [strided](ArrayView::step_by)
+    /// or [reversed](ArrayView::rev) without copying the elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let arr: Array<i32> = (0..6).collect();
+    /// let view = arr.view().slice(1..).step_by(-2);
+    /// assert_eq!(view, [5, 3, 1]);
+    /// assert_eq!((view.offset(), view.stride()), (5, -2));
+    /// ```
+    #[inline]
+    pub fn view(&self) -> ArrayView<'_, T> {
+        // the view borrows the whole array
+        unsafe { ArrayView::new(self.pointer, Strided::contiguous(self.size)) }
+    }
 
-    /// Creates a consuming iterator, that is, one that moves each value out of
-    /// the array (from start to end).
+    /// Returns a mutable view of all the elements of the array.
     ///
-    /// The array cannot be used after calling this.
+    /// Unlike a slice, the view can be [strided](ArrayViewMut::step_by)
+    /// or [reversed](ArrayViewMut::rev) without copying the elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// // interleaved stereo samples
+    /// let mut samples: Array<i32> = vec![1, 1, 2, 2, 3, 3].into();
+    /// let right_channel = samples.view_mut().slice(1..).step_by(2);
+    /// for x in right_channel {
+    ///     *x = -*x;
+    /// }
+    /// assert_eq!(samples, [1, -1, 2, -2, 3, -3]);
+    /// ```
     #[inline]
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(self)
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T> {
+        // the view borrows the whole array mutably
+        unsafe { ArrayViewMut::new(self.pointer, Strided::contiguous(self.size)) }
     }
-}
 
+    /// Returns a multi-dimensional view of the elements, stretched to the `shape`
+    /// by the broadcasting rules, like in NumPy, without copying:
+    /// see [`ArrayViewD::broadcast_to`].
+    ///
+    /// The array is an axis of length `size`, which is aligned with the last axis of the `shape`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let bias: Array<i32> = vec![1, 2, 3].into();
+    /// let rows = bias.broadcast_to(&[2, 3]).unwrap();
+    /// assert_eq!(rows[[1, 2]], 3);
+    /// assert!(bias.broadcast_to(&[3, 2]).is_err());
+    /// ```
+    #[inline]
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<ArrayViewD<'_, T>, ArrayError> {
+        let strided = StridedD::contiguous(&[self.size]).broadcast_to(shape)?;
+        unsafe { Ok(ArrayViewD::new(self.pointer, strided)) }
+    }
 
-impl<T> std::ops::Index<usize> for Array<T> {
-    type Output = T;
+    /// Converts the array into a [`Vec`](std::vec::Vec)
+    ///
+    /// The array cannot be used after calling this.
+    ///
+    /// The memory is reused, if it was allocated by the crate or taken from a vector.
+    /// Otherwise the elements are moved into a new vector and the memory is
+    /// released as the array would do.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        let (ptr, size, backing) = self.into_raw_parts();
+        let ptr = ptr.as_ptr();
+        unsafe {
+            match backing {
+                Backing::Owned => Vec::from_raw_parts(ptr, size, size),
+                Backing::Vec { capacity } => Vec::from_raw_parts(ptr, size, capacity),
+                backing => {
+                    let mut vec = Vec::with_capacity(size);
+                    std::ptr::copy_nonoverlapping(ptr, vec.as_mut_ptr(), size);
+                    vec.set_len(size);
+                    backing.release(ptr, size);
+                    vec
+                }
+            }
+        }
+    }
 
+    /// Converts the array into a [`Box<[T]>`](std::boxed::Box)
+    ///
+    /// The array cannot be used after calling this.
+    ///
+    /// The memory is reused, if it was allocated by the crate or taken from
+    /// a box or a vector without spare capacity. Otherwise the elements are moved
+    /// into a new box (see [`into_vec`](Array::into_vec)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr: Array<i32> = vec![1, 2, 3].into();
+    /// let ptr = arr.as_ptr();
+    /// let boxed: Box<[i32]> = arr.into_boxed_slice();
+    /// assert_eq!(boxed.as_ptr(), ptr);
+    /// ```
     #[inline]
-    #[rustc_on_unimplemented(
-        message = "array indices are of type `usize` or `Index`",
-        label = "array indices are of type `usize` or `Index`"
-    )]
-    fn index(&self, index: usize) -> &Self::Output {
-        self.try_get(index).expect("index out of bounds")
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        // the vector has no spare capacity for the memory allocated by the crate,
+        // so the conversion does not reallocate
+        self.into_vec().into_boxed_slice()
     }
-}
 
+    /// Takes the memory of the vector without copying.
+    #[inline]
+    pub(crate) fn from_vec_buffer(vec: Vec<T>) -> Self {
+        let mut vec = ManuallyDrop::new(vec);
+        // the vector is forgotten, so the array is the only owner of the memory
+        unsafe { Array::from_vec_pointer(vec.as_mut_ptr(), vec.len(), vec.capacity()) }
+    }
 
-impl<T> std::ops::IndexMut<usize> for Array<T> {
+    /// Converts the array into `Array<MaybeUninit<T>>` without copying:
+    /// the elements will not be dropped by it anymore, only the memory will be freed.
+    #[inline]
+    pub(crate) fn into_uninit(self) -> Array<MaybeUninit<T>> {
+        let (ptr, size, backing) = self.into_raw_parts();
+        Array::from_raw_parts(ptr.cast(), size, backing)
+    }
 
     #[inline]
-    #[rustc_on_unimplemented(
-        message = "array indices are of type `usize` or `Index`",
-        label = "array indices are of type `usize` or `Index`"
-    )]
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.try_get_mut(index).expect("index out of bounds")
+    fn from_raw_parts(pointer: NonNull<T>, size: usize, backing: Backing) -> Self {
+        Self { pointer, size, backing, marker: PhantomData }
     }
-}
 
+    /// Disassembles the array without dropping anything.
+    #[inline]
+    fn into_raw_parts(self) -> (NonNull<T>, usize, Backing) {
+        let arr = ManuallyDrop::new(self);
+        // the array is not dropped, so the backing is moved out only once
+        (arr.pointer, arr.size, unsafe { std::ptr::read(&arr.backing) })
+    }
 
-impl<T> Drop for Array<T> {
+    /// Returns immutable raw pointer to the memory, allocated by the array.
+    ///
+    /// The caller must ensure that the array outlives the pointer this
+    /// function returns, or else it will end up pointing to garbage.
+    ///
+    /// The caller must also ensure that the memory the pointer (non-transitively) points to
+    /// is never written to (except inside an `UnsafeCell`) using this pointer or any pointer
+    /// derived from it. If you need to mutate the contents of the array, use [`as_mut_ptr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr: Array<i32> = vec![1,2,4].into();
+    /// let ptr = arr.as_ptr();
+    /// unsafe {
+    ///     for i in 0..arr.size() {
+    ///         assert_eq!(arr[i], *ptr.add(i));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`as_mut_ptr`]: Array::as_mut_ptr
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.pointer.as_ptr().cast_const()
+    }
 
-    fn drop(&mut self) {
-        unsafe { self.pointer.drop_in_place() };
+    /// Returns mutable raw pointer to the memory, allocated by the array.
+    ///
+    /// The caller must ensure that the array outlives the pointer this
+    /// function returns, or else it will end up pointing to garbage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr: Array<i32> = vec![1,2,4].into();
+    /// let ptr = arr.as_mut_ptr();
+    /// unsafe {
+    ///     for i in 0..arr.size() {
+    ///         *ptr.add(i) += 2;
+    ///     }
+    /// }
+    /// ```
+    ///
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.pointer.as_ptr()
     }
+
 }
 
 
-impl<T> From<Vec<T>> for Array<T> {
+impl<T> Array<MaybeUninit<T>> {
 
-    /// Converts a `Vec<T>` to `Array<T>`.
+    /// Initializes an element by the given index with the `value`
+    /// and returns mutable reference to it.
+    ///
+    /// The old content of the element is overwritten without being dropped.
     ///
     /// # Panics
     ///
-    /// if any of the following cases happened:
-    /// * failed creating a [`layout`] with the following size,
-    /// * failed allocating memory for the array.
+    /// if the given index is out of bounds.
     ///
     /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
-    /// let source: Vec<i32> = vec![0, 1, 2, 3];
-    /// let arr : Array<i32> = source.into();
     ///
-    /// for i in 0..4 {
-    ///     assert_eq!(arr[i], i as i32)
-    /// }
+    /// let mut arr = Array::<i32>::new_uninit(1).unwrap();
+    /// *arr.write(0, 5) += 1;
+    /// assert_eq!(unsafe { arr.assume_init() }[0], 6);
     /// ```
-    ///
-    /// [allocating]: std::alloc
-    /// [`layout`]: std::alloc::Layout
-    fn from(vec: Vec<T>) -> Self {
-        let size = vec.len();
-        let mut array = Array::new(size)
-            .expect("failed to create new Array");
-        let mut i = 0_usize;
-        unsafe {
-            for item in vec {
-                *array.get_mut(i) = item;
-                i += 1
-            }
-        }
-        array
+    #[inline]
+    pub fn write(&mut self, index: usize, value: T) -> &mut T {
+        self[index].write(value)
     }
-}
-
-
-impl<T: Clone> Clone for Array<T> {
 
-    /// Copies all elements of one array to another.
+    /// Converts `Array<MaybeUninit<T>>` into `Array<T>` without copying.
     ///
-    /// # Note
+    /// # Safety
     ///
-    /// The elements are copied by value, not by reference.
-    /// So changing elements of the new array will not cause
-    /// changing elements of the old one.
+    /// The caller must guarantee that every element of the array is initialized
+    /// (for example by [`write`](Array::write)). Calling this when the content
+    /// is not yet fully initialized causes immediate undefined behaviour.
     ///
-    /// # Advanced
+    /// # Example
     ///
-    /// That's because multi-handling one pointer will lead to
-    /// undefined behaviour, when one of them is dropped and deallocates
-    /// the memory of it's pointer
-    /// and another one is still trying to use the same pointer.
+    /// ```
+    /// use runtime_sized_array::Array;
     ///
-    /// # Panics
+    /// let mut arr = Array::<String>::new_uninit(1).unwrap();
+    /// arr.write(0, "text".to_string());
+    /// let arr: Array<String> = unsafe { arr.assume_init() };
+    /// assert_eq!(arr[0], "text");
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init(self) -> Array<T> {
+        let (ptr, size, backing) = self.into_raw_parts();
+        Array::from_raw_parts(ptr.cast(), size, backing)
+    }
+}
+
+
+impl<'a, T> IntoIterator for &'a Array<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    /// Returns an iterator over the array.
     ///
-    /// if any of the following cases happened:
-    /// * failed creating a [`layout`] with the following size,
-    /// * failed allocating memory for the array.
+    /// The iterator yields all items from start to end.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use runtime_sized_array::Array;
-    /// let old_arr: Array<i32> = vec![5, 1, 0, 3].into();
-    /// let new_arr : Array<i32> = old_arr.clone();
     ///
-    /// for i in 0..4 {
-    ///     assert_eq!(old_arr[i], new_arr[i]);
+    /// let mut arr: Array<i32> = vec![1,2,4].into();
+    /// for item in &arr {
+    ///     println!("{item}");
     /// }
     /// ```
-    ///
-    /// [allocating]: std::alloc
-    /// [`layout`]: std::alloc::Layout
-    fn clone(&self) -> Self {
-        let arr = Array::new(self.size)
-            .expect("failed to crate new Array");
-        unsafe {
-            for i in 0..self.size {
-                *arr.get_mut_ptr(i) = std::ptr::read(self.get_ptr(i));
-            }
-        }
-        arr
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 
-impl<T> std::ops::Deref for Array<T> {
-    type Target = [T];
+impl<'a, T> IntoIterator for &'a mut Array<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
 
+    /// Returns an iterator that allows modifying each value.
+    ///
+    /// The iterator yields all items from start to end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut arr: Array<i32> = vec![1,2,4].into();
+    /// for elem in &mut arr {
+    ///     *elem += 2;
+    /// }
+    /// assert_eq!(arr[0], 3);
+    /// ```
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { std::slice::from_raw_parts(self.pointer, self.size) }
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
 
-impl<T> std::ops::DerefMut for Array<T> {
+impl<T> IntoIterator for Array<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
 
+    /// Creates a consuming iterator, that is, one that moves each value out of
+    /// the array (from start to end).
+    ///
+    /// The array cannot be used after calling this.
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::slice::from_raw_parts_mut(self.pointer, self.size) }
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
     }
 }
 
 
-// additional functionality
-impl<T> Array<T> {
+impl<T, I> std::ops::Index<I> for Array<T>
+    where I: SliceIndex<[T]>
+{
+    type Output = I::Output;
 
-    /// Tries to take `n` items from the given `iterator` and
-    /// to put them into array of size `n`
-    ///
-    /// If the `iterator` ends, before the array is filled, then
-    /// some elements will be uninitialized
+    /// Returns the element at the `index` or the sub-slice in the range `index`,
+    /// the same way slices do: `arr[2]`, `arr[2..5]`, `arr[..n]`, `arr[3..]`, `arr[..=k]`, `arr[..]`.
     ///
     /// # Panics
     ///
-    /// if any of the following cases happened:
-    /// * failed creating a [`layout`] with the following size,
-    /// * failed allocating memory for the array.
+    /// if the `index` is out of bounds of the array: the message contains
+    /// the index and the size of the array.
     ///
     /// # Example
     ///
     /// ```
     /// use runtime_sized_array::Array;
-    /// let mut iter = vec![0,1,2,3,4,5].into_iter();
-    /// let arr : Array<i32> = Array::take_from_iter(&mut iter, 3);
-    ///
+    /// let arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    /// assert_eq!(arr[1], 2);
+    /// assert_eq!(arr[1..3], [2, 3]);
+    /// assert_eq!(arr[..=1], [1, 2]);
+    /// assert_eq!(arr[3..], [4, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: I) -> &Self::Output {
+        &(**self)[index]
+    }
+}
+
+
+impl<T, I> std::ops::IndexMut<I> for Array<T>
+    where I: SliceIndex<[T]>
+{
+    /// Returns the element at the `index` or the sub-slice in the range `index` mutably.
+    ///
+    /// # Panics
+    ///
+    /// if the `index` is out of bounds of the array: the message contains
+    /// the index and the size of the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    /// arr[0] = 0;
+    /// arr[3..].fill(9);
+    /// assert_eq!(arr, [0, 2, 3, 9, 9]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut (**self)[index]
+    }
+}
+
+
+impl<T> Drop for Array<T> {
+
+    /// Drops all elements of the array and frees its memory
+    /// the way its origin requires: with the same [`layout`] it was allocated with,
+    /// as the source vector would do, by the foreign deallocator or not at all
+    /// for borrowed memory.
+    ///
+    /// [`layout`]: std::alloc::Layout
+    fn drop(&mut self) {
+        let backing = std::mem::replace(&mut self.backing, Backing::Borrowed);
+        // frees the memory after the elements, even if one of their destructors panics
+        let _release = ReleaseGuard { backing, ptr: self.pointer.as_ptr(), size: self.size };
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.pointer.as_ptr(), self.size));
+        }
+    }
+}
+
+
+impl<T> From<Vec<T>> for Array<T> {
+
+    /// Converts a `Vec<T>` to `Array<T>` without copying the elements.
+    ///
+    /// The memory of the vector is reused, after its spare capacity is shrunk.
+    ///
+    /// # Panics
+    ///
+    /// if failed reallocating memory to shrink the vector.
+    ///
+    /// Use [`try_from_vec`](Array::try_from_vec) to handle this case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let source: Vec<i32> = vec![0, 1, 2, 3];
+    /// let arr : Array<i32> = source.into();
+    ///
+    /// for i in 0..4 {
+    ///     assert_eq!(arr[i], i as i32)
+    /// }
+    /// ```
+    fn from(vec: Vec<T>) -> Self {
+        Array::try_from_vec(vec)
+            .expect("failed to create new Array")
+    }
+}
+
+
+impl<T> From<Box<[T]>> for Array<T> {
+
+    /// Converts a `Box<[T]>` to `Array<T>` without copying the elements:
+    /// the memory of the box is reused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let source: Box<[i32]> = Box::new([0, 1, 2]);
+    /// let ptr = source.as_ptr();
+    /// let arr : Array<i32> = source.into();
+    /// assert_eq!(arr.as_ptr(), ptr);
+    /// ```
+    #[inline]
+    fn from(boxed: Box<[T]>) -> Self {
+        let size = boxed.len();
+        let ptr = Box::into_raw(boxed) as *mut T;
+        // a box allocates slices by the global allocator with `Layout::array`
+        unsafe { Array::from_pointer(ptr, size) }
+    }
+}
+
+
+impl<T, const N: usize> From<[T; N]> for Array<T> {
+
+    /// Moves the elements of a `[T; N]` into a new `Array<T>`.
+    ///
+    /// # Panics
+    ///
+    /// if failed allocating memory for the array.
+    ///
+    /// Use [`try_from_array`](Array::try_from_array) to handle this case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr : Array<String> = ["a".to_string(), "b".to_string()].into();
+    /// assert_eq!(arr[1], "b");
+    /// ```
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        Array::try_from_array(array)
+            .expect("failed to create new Array")
+    }
+}
+
+
+impl<T: Clone> From<&[T]> for Array<T> {
+
+    /// Clones the elements of a slice into a new `Array<T>`.
+    ///
+    /// # Panics
+    ///
+    /// if failed allocating memory for the array or `T::clone` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let source = ["a".to_string(), "b".to_string()];
+    /// let arr : Array<String> = source[1..].into();
+    /// assert_eq!(&*arr, &["b"]);
+    /// ```
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        // `to_vec` is panic-safe and copies `Copy` types by `memcpy`
+        Array::from_vec_buffer(slice.to_vec())
+    }
+}
+
+
+impl<T, const N: usize> TryFrom<Array<T>> for [T; N] {
+    type Error = Array<T>;
+
+    /// Moves the elements of the array into a `[T; N]`.
+    ///
+    /// Gives the array back, if its size is not `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr : Array<i32> = vec![1, 2, 3].into();
+    /// let arr: Array<i32> = match <[i32; 2]>::try_from(arr) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(arr) => arr
+    /// };
+    /// let array: [i32; 3] = arr.try_into().ok().unwrap();
+    /// assert_eq!(array, [1, 2, 3]);
+    /// ```
+    fn try_from(array: Array<T>) -> Result<Self, Self::Error> {
+        if array.size() != N {
+            return Err(array);
+        }
+        // the elements are moved out, the memory is freed by the uninit array
+        let array = array.into_uninit();
+        Ok(unsafe { std::ptr::read(array.as_ptr() as *const [T; N]) })
+    }
+}
+
+
+impl<T, const N: usize> TryFrom<Array<T>> for Box<[T; N]> {
+    type Error = Array<T>;
+
+    /// Converts the array into a `Box<[T; N]>`.
+    ///
+    /// The memory is reused, if the array has allocated it itself
+    /// (see [`into_boxed_slice`](Array::into_boxed_slice)).
+    /// Gives the array back, if its size is not `N`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr : Array<i32> = vec![1, 2, 3].into();
+    /// let boxed: Box<[i32; 3]> = arr.try_into().ok().unwrap();
+    /// assert_eq!(*boxed, [1, 2, 3]);
+    /// ```
+    fn try_from(array: Array<T>) -> Result<Self, Self::Error> {
+        if array.size() != N {
+            return Err(array);
+        }
+        let boxed = array.into_boxed_slice();
+        // the length is checked above
+        Ok(unsafe { Box::from_raw(Box::into_raw(boxed) as *mut [T; N]) })
+    }
+}
+
+
+impl<T> FromIterator<T> for Array<T> {
+
+    /// Collects the items of the iterator into a new `Array<T>`.
+    ///
+    /// If the iterator reports its exact length by [`size_hint`](Iterator::size_hint)
+    /// (e.g. is [`ExactSizeIterator`]), the items are written in place
+    /// without an intermediate buffer. Otherwise they are collected
+    /// into a temporary vector, which is shrunk and reused by the array.
+    ///
+    /// A wrong size hint only costs a copy: the result always has all the items.
+    ///
+    /// # Panics
+    ///
+    /// if failed allocating memory for the array or the iterator panicked.
+    ///
+    /// Use [`try_from_iter`](Array::try_from_iter) to handle the allocation failures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let squares: Array<i32> = (1..4).map(|x| x * x).collect();
+    /// assert_eq!(&*squares, &[1, 4, 9]);
+    ///
+    /// let even: Array<i32> = (1..7).filter(|x| x % 2 == 0).collect();
+    /// assert_eq!(&*even, &[2, 4, 6]);
+    /// ```
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Array::try_from_iter(iter)
+            .expect("failed to create new Array")
+    }
+}
+
+
+impl<T: Clone> Clone for Array<T> {
+
+    /// Copies all elements of one array to another.
+    ///
+    /// # Note
+    ///
+    /// The elements are copied by value, not by reference.
+    /// So changing elements of the new array will not cause
+    /// changing elements of the old one.
+    ///
+    /// # Advanced
+    ///
+    /// Every element is cloned by [`T::clone`](Clone::clone), for `T: Copy`
+    /// the memory is just copied. If one of the clones panics, the already
+    /// cloned elements are dropped and the new memory is freed.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * `T::clone` panicked.
+    ///
+    /// Use [`try_clone`](Array::try_clone) to handle allocation failures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let old_arr: Array<i32> = vec![5, 1, 0, 3].into();
+    /// let new_arr : Array<i32> = old_arr.clone();
+    ///
+    /// for i in 0..4 {
+    ///     assert_eq!(old_arr[i], new_arr[i]);
+    /// }
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    fn clone(&self) -> Self {
+        self.try_clone()
+            .expect("failed to crate new Array")
+    }
+
+    /// Clones all elements of `source` into `self`.
+    ///
+    /// If the sizes of the arrays are equal, the memory of `self` is reused
+    /// and the elements are cloned by [`T::clone_from`](Clone::clone_from),
+    /// otherwise `self` is replaced with a new clone of `source`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let source: Array<i32> = vec![5, 1, 0].into();
+    /// let mut arr: Array<i32> = vec![0, 0, 0].into();
+    /// let ptr = arr.as_ptr();
+    ///
+    /// arr.clone_from(&source);
+    /// assert_eq!(&*arr, &[5, 1, 0]);
+    /// assert_eq!(arr.as_ptr(), ptr);
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        if self.size == source.size {
+            self.clone_from_slice(source)
+        } else {
+            *self = source.clone()
+        }
+    }
+}
+
+
+impl<T> std::ops::Deref for Array<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { std::slice::from_raw_parts(self.pointer.as_ptr(), self.size) }
+    }
+}
+
+
+impl<T> std::ops::DerefMut for Array<T> {
+
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.size) }
+    }
+}
+
+
+impl<T: fmt::Debug> fmt::Debug for Array<T> {
+
+    /// Formats the array like a slice: `[1, 2, 3]`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+
+impl<T> Default for Array<T> {
+
+    /// Creates an empty array, which allocates nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr: Array<String> = Array::default();
+    /// assert_eq!(arr.size(), 0);
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Array::from_raw_parts(NonNull::dangling(), 0, Backing::Owned)
+    }
+}
+
+
+impl<T: Hash> Hash for Array<T> {
+
+    /// Hashes the array exactly like the slice of its elements,
+    /// so `Array<T>` and `[T]` can be used as keys of the same map (see [`Borrow`]).
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+
+impl<T, U> PartialEq<Array<U>> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T: Eq> Eq for Array<T> {}
+
+
+impl<T: PartialOrd> PartialOrd for Array<T> {
+
+    /// Compares the arrays [lexicographically](Ord#lexicographical-comparison),
+    /// like slices.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+
+impl<T: Ord> Ord for Array<T> {
+
+    /// Compares the arrays [lexicographically](Ord#lexicographical-comparison),
+    /// like slices.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+
+// comparisons with the other sequences, in both directions
+
+impl<T, U> PartialEq<[U]> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        **self == *other
+    }
+}
+
+
+impl<T, U> PartialEq<&[U]> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &&[U]) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T, U> PartialEq<&mut [U]> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &&mut [U]) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T, U, const N: usize> PartialEq<[U; N]> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &[U; N]) -> bool {
+        **self == other[..]
+    }
+}
+
+
+impl<T, U> PartialEq<Vec<U>> for Array<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Vec<U>) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T, U> PartialEq<Array<U>> for [T]
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        *self == **other
+    }
+}
+
+
+impl<T, U> PartialEq<Array<U>> for &[T]
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T, U> PartialEq<Array<U>> for &mut [T]
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T, U, const N: usize> PartialEq<Array<U>> for [T; N]
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        self[..] == **other
+    }
+}
+
+
+impl<T, U> PartialEq<Array<U>> for Vec<T>
+    where T: PartialEq<U>
+{
+    #[inline]
+    fn eq(&self, other: &Array<U>) -> bool {
+        **self == **other
+    }
+}
+
+
+impl<T> AsRef<[T]> for Array<T> {
+
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+
+impl<T> AsMut<[T]> for Array<T> {
+
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+
+impl<T> Borrow<[T]> for Array<T> {
+
+    /// Borrows the elements as a slice.
+    ///
+    /// `Array<T>` compares and hashes like `[T]`,
+    /// so maps with array keys can be looked up by slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut map: HashMap<Array<u8>, i32> = HashMap::new();
+    /// map.insert(b"key".as_slice().into(), 1);
+    /// assert_eq!(map.get(b"key".as_slice()), Some(&1));
+    /// ```
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+
+impl<T> BorrowMut<[T]> for Array<T> {
+
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+
+// additional functionality
+impl<T> Array<T> {
+
+    /// Takes `n` items from the given `iterator` and
+    /// puts them into array of size `n`
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * the `iterator` ended before `n` items were taken.
+    ///
+    /// Use [`try_take_from_iter`](Array::try_take_from_iter) to handle these cases,
+    /// [`take_from_iter_or`](Array::take_from_iter_or) or
+    /// [`take_from_iter_partial`](Array::take_from_iter_partial) to accept short iterators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut iter = vec![0,1,2,3,4,5].into_iter();
+    /// let arr : Array<i32> = Array::take_from_iter(&mut iter, 3);
+    ///
     /// for i in 0..3 {
     ///     assert_eq!(arr[i], i as i32)
     /// }
@@ -626,21 +1778,767 @@ impl<T> Array<T> {
     ///
     /// [allocating]: std::alloc
     /// [`layout`]: std::alloc::Layout
-    pub fn take_from_iter<I: Iterator>(iterator: &mut I, n: usize) -> Self
+    pub fn take_from_iter<I>(iterator: &mut I, n: usize) -> Self
+        where
+            I : Iterator,
+            T : From<I::Item>
+    {
+        Array::try_take_from_iter(iterator, n)
+            .expect("failed to create new Array")
+    }
+
+    /// Takes `n` items from the given `iterator` and
+    /// puts them into array of size `n`.
+    ///
+    /// Fallible version of [`take_from_iter`](Array::take_from_iter):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array,
+    /// * the `iterator` ended before `n` items were taken
+    ///   ([`IteratorExhausted`](ArrayError::IteratorExhausted)).
+    ///
+    /// The items, which were already taken, are dropped in the case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, ArrayError};
+    /// let mut iter = vec![0,1,2,3,4,5].into_iter();
+    /// let arr : Array<i32> = Array::try_take_from_iter(&mut iter, 3).unwrap();
+    /// assert_eq!(&*arr, &[0, 1, 2]);
+    ///
+    /// let err = Array::<i32>::try_take_from_iter(&mut iter, 5).err();
+    /// assert_eq!(err, Some(ArrayError::IteratorExhausted { wanted: 5, got: 3 }));
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_take_from_iter<I>(iterator: &mut I, n: usize) -> Result<Self, ArrayError>
+        where
+            I : Iterator,
+            T : From<I::Item>
+    {
+        let mut arr = PartialArray::new(n)?;
+        for val in iterator.take(n) {
+            arr.push(val.into());
+        }
+        if arr.len() < n {
+            Err(ArrayError::IteratorExhausted { wanted: n, got: arr.len() })
+        } else {
+            Ok(arr.finish())
+        }
+    }
+
+    /// Takes `n` items from the given `iterator` and puts them into array of size `n`.
+    /// If the `iterator` ends before, the rest of the array is filled with clones of `fill`.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array.
+    ///
+    /// Use [`try_take_from_iter_or`](Array::try_take_from_iter_or) to handle these cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut iter = vec![1, 2].into_iter();
+    /// let arr : Array<i32> = Array::take_from_iter_or(&mut iter, 4, 0);
+    /// assert_eq!(&*arr, &[1, 2, 0, 0]);
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    pub fn take_from_iter_or<I>(iterator: &mut I, n: usize, fill: T) -> Self
+        where
+            I : Iterator,
+            T : From<I::Item> + Clone
+    {
+        Array::try_take_from_iter_or(iterator, n, fill)
+            .expect("failed to create new Array")
+    }
+
+    /// Takes `n` items from the given `iterator` and puts them into array of size `n`.
+    /// If the `iterator` ends before, the rest of the array is filled with clones of `fill`.
+    ///
+    /// Fallible version of [`take_from_iter_or`](Array::take_from_iter_or):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut iter = vec![1, 2].into_iter();
+    /// let arr : Array<i32> = Array::try_take_from_iter_or(&mut iter, 3, 7).unwrap();
+    /// assert_eq!(&*arr, &[1, 2, 7]);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_take_from_iter_or<I>(iterator: &mut I, n: usize, fill: T)
+        -> Result<Self, ArrayError>
+        where
+            I : Iterator,
+            T : From<I::Item> + Clone
+    {
+        let mut arr = PartialArray::new(n)?;
+        for val in iterator.take(n) {
+            arr.push(val.into());
+        }
+        while arr.len() < n {
+            arr.push(fill.clone());
+        }
+        Ok(arr.finish())
+    }
+
+    /// Takes at most `n` items from the given `iterator` and puts them into array.
+    ///
+    /// Returns the array, sized to the number of items the `iterator` actually produced,
+    /// and that number.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array.
+    ///
+    /// Use [`try_take_from_iter_partial`](Array::try_take_from_iter_partial) to handle these cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut iter = vec![1, 2].into_iter();
+    /// let (arr, n) = Array::<i32>::take_from_iter_partial(&mut iter, 4);
+    /// assert_eq!(n, 2);
+    /// assert_eq!(&*arr, &[1, 2]);
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    pub fn take_from_iter_partial<I>(iterator: &mut I, n: usize) -> (Self, usize)
+        where
+            I : Iterator,
+            T : From<I::Item>
+    {
+        Array::try_take_from_iter_partial(iterator, n)
+            .expect("failed to create new Array")
+    }
+
+    /// Takes at most `n` items from the given `iterator` and puts them into array.
+    ///
+    /// Returns the array, sized to the number of items the `iterator` actually produced,
+    /// and that number.
+    ///
+    /// Fallible version of [`take_from_iter_partial`](Array::take_from_iter_partial):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let mut iter = vec![1, 2, 3].into_iter();
+    /// let (arr, n) = Array::<i32>::try_take_from_iter_partial(&mut iter, 2).unwrap();
+    /// assert_eq!(n, 2);
+    /// assert_eq!(&*arr, &[1, 2]);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_take_from_iter_partial<I>(iterator: &mut I, n: usize)
+        -> Result<(Self, usize), ArrayError>
+        where
+            I : Iterator,
+            T : From<I::Item>
+    {
+        let mut arr = PartialArray::new(n)?;
+        for val in iterator.take(n) {
+            arr.push(val.into());
+        }
+        let got = arr.len();
+        if got == n {
+            return Ok((arr.finish(), got));
+        }
+        let mut shrunk = PartialArray::new(got)?;
+        unsafe {
+            // the elements are moved: only `shrunk` drops them from now on
+            std::ptr::copy_nonoverlapping(arr.array.as_ptr(), shrunk.array.as_mut_ptr(), got);
+            arr.initialized = 0;
+            shrunk.initialized = got;
+        }
+        Ok((shrunk.finish(), got))
+    }
+
+    /// Moves all the items of an exact-size iterator into a new array of size
+    /// [`len`](ExactSizeIterator::len).
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * the iterator produced less or more items than its `len` reported.
+    ///
+    /// Use [`try_from_exact_iter`](Array::try_from_exact_iter) to handle these cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::from_exact_iter([1, 2, 3].iter().rev());
+    /// assert_eq!(&*arr, &[&3, &2, &1]);
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    pub fn from_exact_iter<I>(iter: I) -> Self
+        where
+            I : IntoIterator<Item = T>,
+            I::IntoIter : ExactSizeIterator
+    {
+        Array::try_from_exact_iter(iter)
+            .expect("failed to create new Array")
+    }
+
+    /// Moves all the items of an exact-size iterator into a new array of size
+    /// [`len`](ExactSizeIterator::len).
+    ///
+    /// Fallible version of [`from_exact_iter`](Array::from_exact_iter):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array,
+    /// * the iterator ended before `len` items were taken
+    ///   ([`IteratorExhausted`](ArrayError::IteratorExhausted)),
+    /// * the iterator produced more than `len` items
+    ///   ([`LengthMismatch`](ArrayError::LengthMismatch), `found` is `len + 1`,
+    ///   only a lower bound of the number of the items, the rest of them are not taken).
+    ///
+    /// The items, which were already taken, are dropped in the case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::try_from_exact_iter(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(&*arr, &[1, 2, 3]);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_from_exact_iter<I>(iter: I) -> Result<Self, ArrayError>
         where
-            I : Iterator,
-            T : From<I::Item>
+            I : IntoIterator<Item = T>,
+            I::IntoIter : ExactSizeIterator
+    {
+        let mut iter = iter.into_iter();
+        let n = iter.len();
+        let arr = Array::try_take_from_iter(&mut iter, n)?;
+        match iter.next() {
+            None => Ok(arr),
+            // `len` is not trusted: the iterator may be implemented wrongly,
+            // even be endless, so the rest of it is not counted
+            Some(_) => Err(ArrayError::LengthMismatch { expected: n, found: n.saturating_add(1) }),
+        }
+    }
+
+    /// Moves the elements of a `[T; N]` into a new `Array<T>`
+    /// or returns `ArrayError` if failed [allocating] memory for the array.
+    ///
+    /// Fallible version of the `From<[T; N]>` conversion.
+    /// The elements are dropped in the case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::try_from_array([1, 2, 3]).unwrap();
+    /// assert_eq!(&*arr, &[1, 2, 3]);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    pub fn try_from_array<const N: usize>(array: [T; N]) -> Result<Self, ArrayError> {
+        let arr = Array::new_uninit(N)?;
+        let array = ManuallyDrop::new(array);
+        unsafe {
+            // the elements are moved: the source array is forgotten
+            std::ptr::copy_nonoverlapping(array.as_ptr(), arr.as_mut_ptr() as *mut T, N);
+            Ok(arr.assume_init())
+        }
+    }
+
+    /// Collects the items of the iterator into a new `Array<T>`
+    /// or returns `ArrayError` if failed [allocating] memory for the array.
+    ///
+    /// Fallible version of [`collect`](Iterator::collect):
+    /// the items of an iterator with the exact [`size_hint`](Iterator::size_hint)
+    /// are written in place, the others are collected into a temporary vector.
+    ///
+    /// The items, which were already taken, are dropped in the case of an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::try_from_iter("abc".chars()).unwrap();
+    /// assert_eq!(&*arr, &['a', 'b', 'c']);
+    ///
+    /// assert!(Array::try_from_iter(std::iter::repeat(0_u64).take(usize::MAX)).is_err());
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, ArrayError>
+        where I: IntoIterator<Item = T>
     {
-        let mut arr = Array::new(n)
-            .expect("failed to create new Array");
+        let mut iter = iter.into_iter();
+        let n = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => lower,
+            _ => {
+                let mut vec = Vec::new();
+                try_extend_vec(&mut vec, iter)?;
+                return Array::try_from_vec(vec);
+            }
+        };
+        let mut arr = PartialArray::new(n)?;
+        for val in iter.by_ref().take(n) {
+            arr.push(val);
+        }
+        if arr.len() < n {
+            return Array::try_from_vec(arr.try_into_vec()?);
+        }
+        match iter.next() {
+            None => Ok(arr.finish()),
+            Some(val) => {
+                let mut vec = arr.try_into_vec()?;
+                try_extend_vec(&mut vec, std::iter::once(val).chain(iter))?;
+                Array::try_from_vec(vec)
+            }
+        }
+    }
+
+    /// Converts a `Vec<T>` to `Array<T>` without copying the elements
+    /// or returns `ArrayError` if failed [reallocating] memory of the vector.
+    ///
+    /// The memory of the vector is reused. If the vector has spare capacity,
+    /// the memory is shrunk to fit the elements like
+    /// [`Vec::shrink_to_fit`](std::vec::Vec::shrink_to_fit) does,
+    /// but the failure is reported instead of aborting.
+    ///
+    /// Fallible version of the `From<Vec<T>>` conversion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::try_from_vec(vec![0, 1, 2]).unwrap();
+    /// assert_eq!(&*arr, &[0, 1, 2]);
+    /// ```
+    ///
+    /// [reallocating]: std::alloc::realloc
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, ArrayError> {
+        let mut vec = ManuallyDrop::new(vec);
+        let (ptr, size, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        if size == capacity || std::mem::size_of::<T>() == 0 {
+            // nothing to shrink
+            return Ok(unsafe { Array::from_vec_pointer(ptr, size, capacity) });
+        }
         unsafe {
-            for i in 0..n {
-                match iterator.next() {
-                    None => break,
-                    Some(val) => *arr.get_mut_ptr(i) = val.into()
+            // the vector was allocated by the global allocator with this layout
+            let old_layout = Layout::array::<T>(capacity).unwrap_unchecked();
+            let layout = Layout::array::<T>(size).unwrap_unchecked();
+            if size == 0 {
+                std::alloc::dealloc(ptr as *mut u8, old_layout);
+                return Ok(Array::from_raw_parts(NonNull::dangling(), 0, Backing::Owned));
+            }
+            let shrunk = std::alloc::realloc(ptr as *mut u8, old_layout, layout.size());
+            match NonNull::new(shrunk as *mut T) {
+                Some(ptr) => Ok(Array::from_raw_parts(ptr, size, Backing::Owned)),
+                None => {
+                    // the memory of the vector is still valid
+                    ManuallyDrop::drop(&mut vec);
+                    Err(ArrayError::AllocFailed { layout })
                 }
             }
         }
-        arr
     }
-}
\ No newline at end of file
+
+    /// Copies all elements of the array to a new one or returns `ArrayError`
+    /// if failed [allocating] memory for the array.
+    ///
+    /// Fallible version of [`clone`](Clone::clone).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let old_arr: Array<String> = vec!["a".to_string()].into();
+    /// let new_arr = old_arr.try_clone().unwrap();
+    /// assert_eq!(&*new_arr, &*old_arr);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    pub fn try_clone(&self) -> Result<Self, ArrayError>
+        where T: Clone
+    {
+        let layout = Layout::array::<T>(self.size)
+            .map_err(|err| ArrayError::layout_overflow::<T>(self.size, err))?;
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(self.size)
+            .map_err(|_| ArrayError::AllocFailed { layout })?;
+        // `extend_from_slice` is panic-safe and copies `Copy` types by `memcpy`
+        vec.extend_from_slice(self);
+        Ok(Array::from_vec_buffer(vec))
+    }
+
+    /// Creates an array of size `n`, where each element is `f(index)`.
+    ///
+    /// The elements are made in order, from the first to the last.
+    /// If `f` panics, the elements, which were already made, are dropped.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * `f` panicked.
+    ///
+    /// Use [`try_from_fn`](Array::try_from_fn) to handle the first two cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let (width, height) = (3, 2);
+    /// let grid = Array::from_fn(width * height, |i| (i % width, i / width));
+    /// assert_eq!(grid[4], (1, 1));
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    pub fn from_fn<F>(n: usize, mut f: F) -> Self
+        where F: FnMut(usize) -> T
+    {
+        Array::try_from_fn(n, |i| Ok::<T, ArrayError>(f(i)))
+            .expect("failed to create new Array")
+    }
+
+    /// Creates an array of size `n`, where each element is `f(index)`,
+    /// or returns the first error, `f` returned.
+    ///
+    /// The elements are made in order, from the first to the last,
+    /// `f` is not called after the first error.
+    /// The elements, which were already made, are dropped in the case of an error or a panic.
+    ///
+    /// Fallible version of [`from_fn`](Array::from_fn): the `ArrayError` is converted to `E`,
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Panics
+    ///
+    /// if `f` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::error::Error;
+    /// use runtime_sized_array::Array;
+    /// let words = ["1", "2", "x", "4"];
+    /// let parse = |i: usize| -> Result<i32, Box<dyn Error>> { Ok(words[i].parse()?) };
+    ///
+    /// let arr = Array::try_from_fn(2, parse).unwrap();
+    /// assert_eq!(&*arr, &[1, 2]);
+    ///
+    /// assert!(Array::try_from_fn(4, parse).is_err());
+    /// assert!(Array::<u64>::try_from_fn(usize::MAX, |_| Ok::<_, Box<dyn Error>>(0)).is_err());
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_from_fn<E, F>(n: usize, mut f: F) -> Result<Self, E>
+        where
+            E: From<ArrayError>,
+            F: FnMut(usize) -> Result<T, E>
+    {
+        let mut arr = PartialArray::new(n)?;
+        for i in 0..n {
+            arr.push(f(i)?);
+        }
+        Ok(arr.finish())
+    }
+
+    /// Creates an array of size `n`, filled with clones of `value`.
+    ///
+    /// The `value` itself is moved to the last element, so only `n - 1` clones are made.
+    /// If cloning panics, the clones, which were already made, are dropped.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * `T::clone` panicked.
+    ///
+    /// Use [`try_filled`](Array::try_filled) to handle the first two cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::filled(3, "a".to_string());
+    /// assert_eq!(&*arr, &["a", "a", "a"]);
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn filled(n: usize, value: T) -> Self
+        where T: Clone
+    {
+        Array::try_filled(n, value)
+            .expect("failed to create new Array")
+    }
+
+    /// Creates an array of size `n`, filled with clones of `value`.
+    ///
+    /// Fallible version of [`filled`](Array::filled):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Panics
+    ///
+    /// if `T::clone` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::try_filled(2, 'a').unwrap();
+    /// assert_eq!(&*arr, &['a', 'a']);
+    /// assert!(Array::try_filled(usize::MAX, 'a').is_err());
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    pub fn try_filled(n: usize, value: T) -> Result<Self, ArrayError>
+        where T: Clone
+    {
+        let mut arr = PartialArray::new(n)?;
+        if n == 0 {
+            return Ok(arr.finish());
+        }
+        for _ in 1..n {
+            arr.push(value.clone());
+        }
+        arr.push(value);
+        Ok(arr.finish())
+    }
+
+    /// Creates an array of size `n`, filled with `T::default()`.
+    ///
+    /// If `T::default` panics, the elements, which were already made, are dropped.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * `T::default` panicked.
+    ///
+    /// Use [`try_from_default`](Array::try_from_default) to handle the first two cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr: Array<Vec<i32>> = Array::from_default(2);
+    /// assert!(arr.iter().all(Vec::is_empty));
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn from_default(n: usize) -> Self
+        where T: Default
+    {
+        Array::try_from_default(n)
+            .expect("failed to create new Array")
+    }
+
+    /// Creates an array of size `n`, filled with `T::default()`.
+    ///
+    /// Fallible version of [`from_default`](Array::from_default):
+    /// returns `ArrayError`, if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Panics
+    ///
+    /// if `T::default` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr: Array<String> = Array::try_from_default(2).unwrap();
+    /// assert_eq!(&*arr, &["", ""]);
+    /// assert!(Array::<String>::try_from_default(usize::MAX).is_err());
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn try_from_default(n: usize) -> Result<Self, ArrayError>
+        where T: Default
+    {
+        Array::try_from_fn(n, |_| Ok(T::default()))
+    }
+
+    /// Creates an array of size `n`, filled with clones of `elem`,
+    /// like `vec![elem; n]` does.
+    ///
+    /// The same as [`filled`](Array::filled), named after `vec!`.
+    ///
+    /// # Panics
+    ///
+    /// if any of the following cases happened:
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed allocating memory for the array,
+    /// * `T::clone` panicked.
+    ///
+    /// Use [`try_from_elem`](Array::try_from_elem) to handle the first two cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    /// let arr = Array::from_elem(1000, 0.0_f64);
+    /// assert!(arr.iter().all(|&x| x == 0.0));
+    /// ```
+    ///
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn from_elem(n: usize, elem: T) -> Self
+        where T: Clone
+    {
+        Array::try_from_elem(n, elem)
+            .expect("failed to create new Array")
+    }
+
+    /// Creates an array of size `n`, filled with clones of `elem`.
+    ///
+    /// Fallible version of [`from_elem`](Array::from_elem),
+    /// the same as [`try_filled`](Array::try_filled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, ArrayError};
+    /// let arr = Array::try_from_elem(3, 0_u8).unwrap();
+    /// assert_eq!(&*arr, &[0, 0, 0]);
+    /// assert!(matches!(
+    ///     Array::try_from_elem(usize::MAX, 0_u16),
+    ///     Err(ArrayError::LayoutOverflow { .. })
+    /// ));
+    /// ```
+    #[inline]
+    pub fn try_from_elem(n: usize, elem: T) -> Result<Self, ArrayError>
+        where T: Clone
+    {
+        Array::try_filled(n, elem)
+    }
+}
+
+
+/// Pushes the items of the iterator into the vector, growing it like `Vec::extend` does,
+/// but reporting the allocation failures.
+fn try_extend_vec<T>(vec: &mut Vec<T>, iter: impl Iterator<Item = T>) -> Result<(), ArrayError> {
+    let mut iter = iter.peekable();
+    while iter.peek().is_some() {
+        let wanted = vec.len().saturating_add(iter.size_hint().0.max(1));
+        vec.try_reserve(wanted - vec.len())
+            .map_err(|_| ArrayError::alloc_failed::<T>(wanted))?;
+        // the reserved capacity is filled without reallocating
+        while vec.len() < vec.capacity() {
+            match iter.next() {
+                Some(val) => vec.push(val),
+                None => return Ok(()),
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/// An array under construction: the first `initialized` elements are written.
+///
+/// If it is dropped before being [finished](PartialArray::finish)
+/// (because of a panic or an error), the written elements are dropped
+/// and the memory is freed.
+struct PartialArray<T> {
+    array: Array<MaybeUninit<T>>,
+    initialized: usize,
+}
+
+
+impl<T> PartialArray<T> {
+
+    #[inline]
+    fn new(size: usize) -> Result<Self, ArrayError> {
+        Ok(Self { array: Array::new_uninit(size)?, initialized: 0 })
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.initialized
+    }
+
+    /// Writes the next element.
+    ///
+    /// Panics, if the array is already full.
+    #[inline]
+    fn push(&mut self, value: T) {
+        self.array.write(self.initialized, value);
+        self.initialized += 1;
+    }
+
+    /// Moves the written elements into a vector without spare capacity.
+    ///
+    /// The elements are dropped, if failed allocating memory for the vector.
+    fn try_into_vec(mut self) -> Result<Vec<T>, ArrayError> {
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(self.initialized)
+            .map_err(|_| ArrayError::alloc_failed::<T>(self.initialized))?;
+        unsafe {
+            // the elements are moved: only the vector drops them from now on
+            std::ptr::copy_nonoverlapping(self.array.as_ptr() as *const T, vec.as_mut_ptr(), self.initialized);
+            vec.set_len(self.initialized);
+        }
+        self.initialized = 0;
+        Ok(vec)
+    }
+
+    /// Returns the initialized array.
+    ///
+    /// Panics, if the array is not full.
+    #[inline]
+    fn finish(self) -> Array<T> {
+        assert_eq!(self.initialized, self.array.size(), "array is not fully initialized");
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            // the guard is forgotten, so the array is moved out only once
+            std::ptr::read(&this.array).assume_init()
+        }
+    }
+}
+
+
+impl<T> Drop for PartialArray<T> {
+
+    fn drop(&mut self) {
+        let initialized = self.array.as_mut_ptr() as *mut T;
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(initialized, self.initialized));
+        }
+    }
+}
@@ -0,0 +1,658 @@
+//! Provides the two-dimensional [`Array2`] with a runtime shape
+
+use std::fmt;
+
+use super::{
+    Array, ArrayError, ArrayView, ArrayViewD, ArrayViewMut, ArrayViewMutD, AxisSlice, Lanes, LanesMut, Zeroable
+};
+use super::array_views::{Entries, Strided};
+use super::arrayd_views::StridedD;
+
+
+/// The order, in which the elements of a multi-dimensional array are stored in memory.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::{Array2, Order};
+///
+/// let c = Array2::from_fn_with_order(2, 2, Order::RowMajor, |i, j| 10 * i + j);
+/// let f = Array2::from_fn_with_order(2, 2, Order::ColumnMajor, |i, j| 10 * i + j);
+/// assert_eq!(c.as_slice(), &[0, 1, 10, 11]);
+/// assert_eq!(f.as_slice(), &[0, 10, 1, 11]);
+/// assert_eq!(c, f);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// The rows follow each other, like in C.
+    #[default]
+    RowMajor,
+    /// The columns follow each other, like in Fortran.
+    ColumnMajor,
+}
+
+
+/// Two-dimensional array with `rows` and `cols`, determined at run time.
+///
+/// The elements are stored in one [`Array`] in the row-major (C)
+/// or the column-major (Fortran) [`Order`], so the conversions
+/// to and from the flat array do not copy.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::Array2;
+///
+/// let mut matrix = Array2::from_fn(2, 3, |i, j| i * 3 + j);
+/// assert_eq!((matrix.rows(), matrix.cols()), (2, 3));
+/// assert_eq!(matrix[(1, 2)], 5);
+///
+/// matrix[(0, 0)] = 7;
+/// assert_eq!(matrix.row(0), [7, 1, 2]);
+/// assert_eq!(matrix.col(1), [1, 4]);
+/// ```
+pub struct Array2<T> {
+    data: Array<T>,
+    rows: usize,
+    cols: usize,
+    order: Order,
+}
+
+
+impl<T> Array2<T> {
+
+    /// Creates a row-major matrix of the given shape, filled with zeros.
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * `rows * cols` overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix: Array2<f64> = Array2::new(2, 3).unwrap();
+    /// assert_eq!(matrix[(1, 2)], 0.0);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn new(rows: usize, cols: usize) -> Result<Self, ArrayError>
+        where T: Zeroable
+    {
+        Array2::new_with_order(rows, cols, Order::RowMajor)
+    }
+
+    /// Creates a matrix of the given shape and order, filled with zeros.
+    ///
+    /// See [`new`](Array2::new) for the errors.
+    #[inline]
+    pub fn new_with_order(rows: usize, cols: usize, order: Order) -> Result<Self, ArrayError>
+        where T: Zeroable
+    {
+        let size = rows.checked_mul(cols).ok_or(ArrayError::ShapeOverflow)?;
+        Ok(Array2 { data: Array::new(size)?, rows, cols, order })
+    }
+
+    /// Creates a row-major matrix, where each element is `f(row, col)`.
+    ///
+    /// The elements are made in the order of the memory.
+    /// If `f` panics, the elements, which were already made, are dropped.
+    ///
+    /// # Panics
+    ///
+    /// if `rows * cols` overflows, failed allocating memory for the array or `f` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let identity = Array2::from_fn(3, 3, |i, j| if i == j { 1.0 } else { 0.0 });
+    /// assert_eq!(identity.row(1), [0.0, 1.0, 0.0]);
+    /// ```
+    #[inline]
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+        where F: FnMut(usize, usize) -> T
+    {
+        Array2::from_fn_with_order(rows, cols, Order::RowMajor, f)
+    }
+
+    /// Creates a matrix of the given order, where each element is `f(row, col)`.
+    ///
+    /// See [`from_fn`](Array2::from_fn) for the details.
+    #[inline]
+    pub fn from_fn_with_order<F>(rows: usize, cols: usize, order: Order, mut f: F) -> Self
+        where F: FnMut(usize, usize) -> T
+    {
+        Array2::try_from_fn_with_order(rows, cols, order, |i, j| Ok::<T, ArrayError>(f(i, j)))
+            .expect("failed to create new Array2")
+    }
+
+    /// Creates a row-major matrix, where each element is `f(row, col)`,
+    /// or returns the first error, `f` returned.
+    ///
+    /// The elements are made in the order of the memory, `f` is not called after the first error.
+    /// The elements, which were already made, are dropped in the case of an error or a panic.
+    ///
+    /// Fallible version of [`from_fn`](Array2::from_fn): the `ArrayError` is converted to `E`,
+    /// if any of the following cases happened:
+    /// * `rows * cols` overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Panics
+    ///
+    /// if `f` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array2, ArrayError};
+    ///
+    /// let matrix = Array2::try_from_fn(2, 2, |i, j| Ok::<_, ArrayError>(i + j)).unwrap();
+    /// assert_eq!(matrix.row(1), [1, 2]);
+    ///
+    /// let err = Array2::<u8>::try_from_fn(usize::MAX, 2, |_, _| Ok::<_, ArrayError>(0));
+    /// assert_eq!(err.unwrap_err(), ArrayError::ShapeOverflow);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn try_from_fn<E, F>(rows: usize, cols: usize, f: F) -> Result<Self, E>
+        where
+            E: From<ArrayError>,
+            F: FnMut(usize, usize) -> Result<T, E>
+    {
+        Array2::try_from_fn_with_order(rows, cols, Order::RowMajor, f)
+    }
+
+    /// Creates a matrix of the given order, where each element is `f(row, col)`,
+    /// or returns the first error, `f` returned.
+    ///
+    /// See [`try_from_fn`](Array2::try_from_fn) for the details.
+    pub fn try_from_fn_with_order<E, F>(rows: usize, cols: usize, order: Order, mut f: F) -> Result<Self, E>
+        where
+            E: From<ArrayError>,
+            F: FnMut(usize, usize) -> Result<T, E>
+    {
+        let size = rows.checked_mul(cols).ok_or(ArrayError::ShapeOverflow)?;
+        let data = match order {
+            Order::RowMajor => Array::try_from_fn(size, |k| f(k / cols, k % cols))?,
+            Order::ColumnMajor => Array::try_from_fn(size, |k| f(k % rows, k / rows))?,
+        };
+        Ok(Array2 { data, rows, cols, order })
+    }
+
+    /// Reinterprets the flat array as a matrix of the given shape and order without copying.
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * `rows * cols` overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * `rows * cols` differs from the size of the array
+    ///   ([`LengthMismatch`](ArrayError::LengthMismatch)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, Array2, Order};
+    ///
+    /// let flat: Array<i32> = vec![1, 2, 3, 4, 5, 6].into();
+    /// let matrix = Array2::from_array(flat, 2, 3, Order::RowMajor).unwrap();
+    /// assert_eq!(matrix.row(1), [4, 5, 6]);
+    /// ```
+    pub fn from_array(data: Array<T>, rows: usize, cols: usize, order: Order)
+        -> Result<Self, ArrayError>
+    {
+        let size = rows.checked_mul(cols).ok_or(ArrayError::ShapeOverflow)?;
+        if size != data.size() {
+            return Err(ArrayError::LengthMismatch { expected: size, found: data.size() });
+        }
+        Ok(Array2 { data, rows, cols, order })
+    }
+
+    /// Converts the matrix into the flat array of its elements in the memory order without copying.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix = Array2::from_fn(2, 2, |i, j| 10 * i + j);
+    /// assert_eq!(matrix.into_array(), [0, 1, 10, 11]);
+    /// ```
+    #[inline]
+    pub fn into_array(self) -> Array<T> {
+        self.data
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `(rows, cols)`.
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Returns the order of the elements in memory.
+    #[inline]
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Returns the elements in the memory order as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the elements in the memory order as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Returns the position of the element in memory, the indices must be in bounds.
+    #[inline]
+    fn position(&self, row: usize, col: usize) -> usize {
+        self.order.position(self.shape(), row, col)
+    }
+
+    /// Returns the distances in memory between the neighbouring rows and columns.
+    #[inline]
+    fn strides(&self) -> (isize, isize) {
+        match self.order {
+            Order::RowMajor => (self.cols as isize, 1),
+            Order::ColumnMajor => (1, self.rows as isize),
+        }
+    }
+
+    /// Returns the elements of the first row.
+    #[inline]
+    fn first_row(&self) -> Strided {
+        Strided { offset: 0, len: self.cols, stride: self.strides().1 }
+    }
+
+    /// Returns the elements of the first column.
+    #[inline]
+    fn first_col(&self) -> Strided {
+        Strided { offset: 0, len: self.rows, stride: self.strides().0 }
+    }
+
+    /// Returns immutable reference at an element
+    /// or None if the given indices are out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix = Array2::from_fn(2, 3, |i, j| i * 3 + j);
+    /// assert_eq!(matrix.try_get(1, 0), Some(&3));
+    /// assert_eq!(matrix.try_get(0, 3), None);
+    /// ```
+    #[inline]
+    pub fn try_get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.try_get(self.position(row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Returns mutable reference at an element
+    /// or None if the given indices are out of bounds.
+    #[inline]
+    pub fn try_get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            let position = self.position(row, col);
+            self.data.try_get_mut(position)
+        } else {
+            None
+        }
+    }
+
+    #[track_caller]
+    fn check_index(&self, row: usize, col: usize) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({row}, {col}) is out of bounds of the shape ({}, {})", self.rows, self.cols
+        );
+    }
+
+    /// Returns a view of the row `row`.
+    ///
+    /// # Panics
+    ///
+    /// if `row` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array2, Order};
+    ///
+    /// let matrix = Array2::from_fn_with_order(2, 3, Order::ColumnMajor, |i, j| i * 3 + j);
+    /// assert_eq!(matrix.row(1), [3, 4, 5]);
+    /// assert_eq!(matrix.row(1).stride(), 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn row(&self, row: usize) -> ArrayView<'_, T> {
+        self.check_row(row);
+        let strided = self.first_row().shifted(row, self.strides().0);
+        unsafe { ArrayView::new(self.data.pointer, strided) }
+    }
+
+    /// Returns a mutable view of the row `row`.
+    ///
+    /// # Panics
+    ///
+    /// if `row` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn row_mut(&mut self, row: usize) -> ArrayViewMut<'_, T> {
+        self.check_row(row);
+        let strided = self.first_row().shifted(row, self.strides().0);
+        unsafe { ArrayViewMut::new(self.data.pointer, strided) }
+    }
+
+    /// Returns a view of the column `col`.
+    ///
+    /// # Panics
+    ///
+    /// if `col` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix = Array2::from_fn(2, 3, |i, j| i * 3 + j);
+    /// assert_eq!(matrix.col(2), [2, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn col(&self, col: usize) -> ArrayView<'_, T> {
+        self.check_col(col);
+        let strided = self.first_col().shifted(col, self.strides().1);
+        unsafe { ArrayView::new(self.data.pointer, strided) }
+    }
+
+    /// Returns a mutable view of the column `col`.
+    ///
+    /// # Panics
+    ///
+    /// if `col` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn col_mut(&mut self, col: usize) -> ArrayViewMut<'_, T> {
+        self.check_col(col);
+        let strided = self.first_col().shifted(col, self.strides().1);
+        unsafe { ArrayViewMut::new(self.data.pointer, strided) }
+    }
+
+    #[track_caller]
+    fn check_row(&self, row: usize) {
+        assert!(row < self.rows, "row {row} is out of bounds of {} rows", self.rows);
+    }
+
+    #[track_caller]
+    fn check_col(&self, col: usize) {
+        assert!(col < self.cols, "column {col} is out of bounds of {} columns", self.cols);
+    }
+
+    /// Returns an iterator over the views of the rows.
+    #[inline]
+    pub fn iter_rows(&self) -> Lanes<'_, T> {
+        unsafe { Lanes::new(self.data.pointer, self.first_row(), self.strides().0, self.rows) }
+    }
+
+    /// Returns an iterator over the mutable views of the rows.
+    #[inline]
+    pub fn iter_rows_mut(&mut self) -> LanesMut<'_, T> {
+        unsafe { LanesMut::new(self.data.pointer, self.first_row(), self.strides().0, self.rows) }
+    }
+
+    /// Returns an iterator over the views of the columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix = Array2::from_fn(2, 3, |i, j| i * 3 + j);
+    /// let maxima: Vec<usize> = matrix.iter_cols().map(|col| col[1]).collect();
+    /// assert_eq!(maxima, [3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn iter_cols(&self) -> Lanes<'_, T> {
+        unsafe { Lanes::new(self.data.pointer, self.first_col(), self.strides().1, self.cols) }
+    }
+
+    /// Returns an iterator over the mutable views of the columns.
+    #[inline]
+    pub fn iter_cols_mut(&mut self) -> LanesMut<'_, T> {
+        unsafe { LanesMut::new(self.data.pointer, self.first_col(), self.strides().1, self.cols) }
+    }
+
+    /// Returns the rows and the columns as the two axes of a multi-dimensional view.
+    #[inline]
+    fn strided_d(&self) -> StridedD {
+        let (row_stride, col_stride) = self.strides();
+        StridedD {
+            offset: 0,
+            shape: Array::from(&[self.rows, self.cols][..]),
+            strides: Array::from(&[row_stride, col_stride][..]),
+        }
+    }
+
+    /// Returns a view of the elements, selected by `info`: see [`s!`](crate::s).
+    ///
+    /// The first axis is the rows, the second one is the columns, whatever the [order](Order) is.
+    /// See [`ArrayD::slice`](crate::ArrayD::slice) for the errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{s, Array2, Order};
+    ///
+    /// let matrix = Array2::from_fn_with_order(3, 4, Order::ColumnMajor, |i, j| 10 * i + j);
+    /// let block = matrix.slice(s![1.., 1..;2]).unwrap();
+    /// assert_eq!(block.shape(), &[2, 2]);
+    /// assert_eq!(block.iter().copied().collect::<Vec<_>>(), [11, 13, 21, 23]);
+    ///
+    /// let row = matrix.slice(s![2]).unwrap();
+    /// assert_eq!(row.shape(), &[4]);
+    /// ```
+    #[inline]
+    pub fn slice(&self, info: &[AxisSlice]) -> Result<ArrayViewD<'_, T>, ArrayError> {
+        let strided = self.strided_d().slice(info)?;
+        unsafe { Ok(ArrayViewD::new(self.data.pointer, strided)) }
+    }
+
+    /// Returns a mutable view of the elements, selected by `info`: see [`s!`](crate::s).
+    ///
+    /// See [`ArrayD::slice`](crate::ArrayD::slice) for the errors.
+    #[inline]
+    pub fn slice_mut(&mut self, info: &[AxisSlice]) -> Result<ArrayViewMutD<'_, T>, ArrayError> {
+        let strided = self.strided_d().slice(info)?;
+        unsafe { Ok(ArrayViewMutD::new(self.data.pointer, strided)) }
+    }
+
+    /// Returns a view of the elements stretched to the `shape` by the broadcasting rules,
+    /// like in NumPy, without copying: see [`ArrayViewD::broadcast_to`].
+    ///
+    /// The first axis is the rows, the second one is the columns, whatever the [order](Order) is.
+    #[inline]
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<ArrayViewD<'_, T>, ArrayError> {
+        let strided = self.strided_d().broadcast_to(shape)?;
+        unsafe { Ok(ArrayViewD::new(self.data.pointer, strided)) }
+    }
+
+    /// Transposes the matrix without moving the elements:
+    /// the rows become the columns and the [order](Order) is flipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array2, Order};
+    ///
+    /// let matrix = Array2::from_fn(2, 3, |i, j| i * 3 + j);
+    /// let transposed = matrix.transpose();
+    /// assert_eq!(transposed.shape(), (3, 2));
+    /// assert_eq!(transposed.order(), Order::ColumnMajor);
+    /// assert_eq!(transposed.row(2), [2, 5]);
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Self {
+        let order = match self.order {
+            Order::RowMajor => Order::ColumnMajor,
+            Order::ColumnMajor => Order::RowMajor,
+        };
+        Array2 { data: self.data, rows: self.cols, cols: self.rows, order }
+    }
+
+    /// Rearranges the elements in memory in the given order.
+    ///
+    /// Moves the elements to a new allocation, unless the order is already the same.
+    ///
+    /// # Panics
+    ///
+    /// if failed allocating memory for the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array2, Order};
+    ///
+    /// let matrix = Array2::from_fn(2, 2, |i, j| 10 * i + j);
+    /// let fortran = matrix.into_order(Order::ColumnMajor);
+    /// assert_eq!(fortran.as_slice(), &[0, 10, 1, 11]);
+    /// ```
+    pub fn into_order(self, order: Order) -> Self {
+        if order == self.order {
+            return self;
+        }
+        let Array2 { data, rows, cols, order: old } = self;
+        let source = data.into_uninit();
+        // every element is read once, `source` only frees the memory afterwards
+        Array2::from_fn_with_order(rows, cols, order, |row, col| unsafe {
+            source[old.position((rows, cols), row, col)].assume_init_read()
+        })
+    }
+}
+
+
+impl Order {
+
+    /// Returns the position in memory of the element `(row, col)` of a matrix of the given shape.
+    #[inline]
+    fn position(self, (rows, cols): (usize, usize), row: usize, col: usize) -> usize {
+        match self {
+            Order::RowMajor => row * cols + col,
+            Order::ColumnMajor => col * rows + row,
+        }
+    }
+}
+
+
+impl<T> std::ops::Index<(usize, usize)> for Array2<T> {
+    type Output = T;
+
+    /// Returns the element at `(row, col)`.
+    ///
+    /// # Panics
+    ///
+    /// if the indices are out of bounds: the message contains the indices and the shape.
+    #[inline]
+    #[track_caller]
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.check_index(row, col);
+        &self.data[self.position(row, col)]
+    }
+}
+
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Array2<T> {
+
+    /// Returns the element at `(row, col)` mutably.
+    ///
+    /// # Panics
+    ///
+    /// if the indices are out of bounds: the message contains the indices and the shape.
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.check_index(row, col);
+        let position = self.position(row, col);
+        &mut self.data[position]
+    }
+}
+
+
+impl<T: Clone> Clone for Array2<T> {
+
+    #[inline]
+    fn clone(&self) -> Self {
+        Array2 { data: self.data.clone(), rows: self.rows, cols: self.cols, order: self.order }
+    }
+}
+
+
+impl<T, U> PartialEq<Array2<U>> for Array2<T>
+    where T: PartialEq<U>
+{
+    /// Compares the shapes and the elements at the same indices,
+    /// regardless of the order in memory.
+    fn eq(&self, other: &Array2<U>) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        if self.order == other.order {
+            return *self.data == *other.data;
+        }
+        self.iter_rows().zip(other.iter_rows()).all(|(a, b)| a == b)
+    }
+}
+
+
+impl<T: Eq> Eq for Array2<T> {}
+
+
+impl<T: fmt::Debug> fmt::Debug for Array2<T> {
+
+    /// Formats the matrix as a list of rows: `[[1, 2], [3, 4]]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_rows().map(|row| Entries(row.iter()))).finish()
+    }
+}
+
+
+impl<T> From<Array2<T>> for Array<T> {
+
+    /// Converts the matrix into the flat array of its elements in the memory order
+    /// without copying, like [`into_array`](Array2::into_array).
+    #[inline]
+    fn from(matrix: Array2<T>) -> Self {
+        matrix.into_array()
+    }
+}
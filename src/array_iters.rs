@@ -7,6 +7,8 @@ pub use into_iter::IntoIter;
 
 mod iter {
 
+    use std::fmt;
+    use std::iter::FusedIterator;
     use std::marker::PhantomData;
     use crate::array::Array;
 
@@ -34,21 +36,45 @@ mod iter {
         // otherwise iterator will have access to freed memory
         marker: PhantomData<&'a T>,
         ptr: *const T,
-        end: *const T,
+        // elements are counted instead of comparing pointers,
+        // because the pointer never moves for zero-sized types
+        len: usize,
     }
 
 
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+
     impl<'a, T> Iter<'a, T> {
 
         #[inline]
         pub(crate) fn new(array: &'a Array<T>) -> Self {
-            let ptr = array.pointer;
             Self {
                 marker: PhantomData,
-                ptr,
-                end: unsafe { ptr.add(array.size()) }
+                ptr: array.pointer.as_ptr(),
+                len: array.size()
             }
         }
+
+        /// Returns the remaining items of this iterator as a slice.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let array : Array<i32> = vec![1,2,3].into();
+        /// let mut iter = array.iter();
+        /// iter.next();
+        /// assert_eq!(iter.as_slice(), &[2, 3]);
+        /// ```
+        #[inline]
+        pub fn as_slice(&self) -> &'a [T] {
+            unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        }
     }
 
 
@@ -57,22 +83,96 @@ mod iter {
 
         #[inline]
         fn next(&mut self) -> Option<Self::Item> {
-            if self.ptr == self.end {
+            if self.len == 0 {
                 None
             } else {
                 unsafe {
                     let p = self.ptr;
                     self.ptr = self.ptr.add(1);
+                    self.len -= 1;
                     Some(&*p)
                 }
             }
         }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+
+        #[inline]
+        fn count(self) -> usize {
+            self.len
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if n >= self.len {
+                self.ptr = unsafe { self.ptr.add(self.len) };
+                self.len = 0;
+                None
+            } else {
+                self.ptr = unsafe { self.ptr.add(n) };
+                self.len -= n;
+                self.next()
+            }
+        }
+
+        #[inline]
+        fn last(mut self) -> Option<Self::Item> {
+            self.next_back()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for Iter<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                unsafe { Some(&*self.ptr.add(self.len)) }
+            }
+        }
+
+        #[inline]
+        fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+            self.len -= n.min(self.len);
+            self.next_back()
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+
+    impl<T> FusedIterator for Iter<'_, T> {}
+
+
+    impl<T> Clone for Iter<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            Self { marker: PhantomData, ptr: self.ptr, len: self.len }
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Iter").field(&self.as_slice()).finish()
+        }
     }
 }
 
 
 mod itermut {
 
+    use std::fmt;
+    use std::iter::FusedIterator;
     use std::marker::PhantomData;
     use crate::array::Array;
 
@@ -99,24 +199,67 @@ mod itermut {
     pub struct IterMut<'a, T> {
         // this make borrow checker not let you to drop array before iterator
         // otherwise iterator will have access to freed memory
-        marker: PhantomData<&'a T>,
+        marker: PhantomData<&'a mut T>,
         ptr: *mut T,
-        end: *mut T,
+        // elements are counted instead of comparing pointers,
+        // because the pointer never moves for zero-sized types
+        len: usize,
     }
 
 
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+
     impl<'a, T> IterMut<'a, T> {
 
         #[inline]
         pub(crate) fn new(array: &'a mut Array<T>) -> Self {
-            let ptr = array.pointer;
             let size = array.size();
             Self {
                 marker: PhantomData,
-                ptr,
-                end: unsafe { ptr.add(size) }
+                ptr: array.pointer.as_ptr(),
+                len: size
             }
         }
+
+        /// Returns the remaining items of this iterator as a slice.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array : Array<i32> = vec![1,2,3].into();
+        /// let mut iter = array.iter_mut();
+        /// iter.next();
+        /// assert_eq!(iter.as_slice(), &[2, 3]);
+        /// ```
+        #[inline]
+        pub fn as_slice(&self) -> &[T] {
+            unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        }
+
+        /// Converts the iterator into a mutable slice of the remaining items,
+        /// which lives as long as the array is borrowed.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array : Array<i32> = vec![1,2,3].into();
+        /// let mut iter = array.iter_mut();
+        /// iter.next();
+        /// iter.into_slice()[0] = 5;
+        /// assert_eq!(array[1], 5);
+        /// ```
+        #[inline]
+        pub fn into_slice(self) -> &'a mut [T] {
+            unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+        }
     }
 
 
@@ -125,23 +268,88 @@ mod itermut {
 
         #[inline]
         fn next(&mut self) -> Option<Self::Item> {
-            if self.ptr == self.end {
+            if self.len == 0 {
                 None
             } else {
                 unsafe {
                     let p = self.ptr;
                     self.ptr = self.ptr.add(1);
+                    self.len -= 1;
                     Some(&mut *p)
                 }
             }
         }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+
+        #[inline]
+        fn count(self) -> usize {
+            self.len
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if n >= self.len {
+                self.ptr = unsafe { self.ptr.add(self.len) };
+                self.len = 0;
+                None
+            } else {
+                self.ptr = unsafe { self.ptr.add(n) };
+                self.len -= n;
+                self.next()
+            }
+        }
+
+        #[inline]
+        fn last(mut self) -> Option<Self::Item> {
+            self.next_back()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for IterMut<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                unsafe { Some(&mut *self.ptr.add(self.len)) }
+            }
+        }
+
+        #[inline]
+        fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+            self.len -= n.min(self.len);
+            self.next_back()
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+
+    impl<T> FusedIterator for IterMut<'_, T> {}
+
+
+    impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("IterMut").field(&self.as_slice()).finish()
+        }
     }
 }
 
 
 mod into_iter {
 
-    use std::marker::PhantomData;
+    use std::fmt;
+    use std::iter::FusedIterator;
+    use std::mem::MaybeUninit;
     use crate::array::Array;
 
 
@@ -150,6 +358,9 @@ mod into_iter {
     /// This `struct` is created by the `into_iter` method on [`Array`](Array)
     /// (provided by the [`IntoIterator`] trait).
     ///
+    /// When dropped, it drops the elements, that were not yielded yet,
+    /// and frees the memory of the array.
+    ///
     /// # Example
     ///
     /// ```
@@ -159,10 +370,12 @@ mod into_iter {
     /// let iter: IntoIter<_>  = array.into_iter();
     /// ```
     pub struct IntoIter<T> {
-        // do not let array be dropped before one's time
-        array: Array<T>,
-        ptr: *const T,
-        end: *const T,
+        // owns the memory, but does not drop the elements
+        array: Array<MaybeUninit<T>>,
+        // the elements, which are not yielded yet;
+        // indices work for zero-sized types as well
+        start: usize,
+        end: usize,
     }
 
 
@@ -170,9 +383,66 @@ mod into_iter {
 
         #[inline]
         pub(crate) fn new(array: Array<T>) -> Self {
-            let end = unsafe { array.pointer.add(array.size()) };
-            let ptr = array.pointer.as_const();
-            Self { array, ptr, end }
+            let end = array.size();
+            Self { array: array.into_uninit(), start: 0, end }
+        }
+
+        #[inline]
+        fn ptr(&self) -> *mut T {
+            self.array.as_mut_ptr() as *mut T
+        }
+
+        /// Returns the remaining items of this iterator as a slice.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let array : Array<i32> = vec![1,2,3].into();
+        /// let mut iter = array.into_iter();
+        /// assert_eq!(iter.as_slice(), &[1, 2, 3]);
+        /// let _ = iter.next().unwrap();
+        /// assert_eq!(iter.as_slice(), &[2, 3]);
+        /// ```
+        #[inline]
+        pub fn as_slice(&self) -> &[T] {
+            unsafe { std::slice::from_raw_parts(self.ptr().add(self.start), self.len()) }
+        }
+
+        /// Returns the remaining items of this iterator as a mutable slice.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let array : Array<i32> = vec![1,2,3].into();
+        /// let mut iter = array.into_iter();
+        /// iter.as_mut_slice()[2] = 5;
+        /// assert_eq!(iter.last(), Some(5));
+        /// ```
+        #[inline]
+        pub fn as_mut_slice(&mut self) -> &mut [T] {
+            unsafe { std::slice::from_raw_parts_mut(self.ptr().add(self.start), self.len()) }
+        }
+
+        /// Drops the elements `start..start + n` (which must be not yielded yet).
+        #[inline]
+        fn drop_front(&mut self, n: usize) {
+            let ptr = unsafe { self.ptr().add(self.start) };
+            // the elements are excluded before dropping,
+            // so even a panicking destructor will not make them be dropped twice
+            self.start += n;
+            unsafe { std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(ptr, n)) }
+        }
+
+        /// Drops the elements `end - n..end` (which must be not yielded yet).
+        #[inline]
+        fn drop_back(&mut self, n: usize) {
+            self.end -= n;
+            let ptr = unsafe { self.ptr().add(self.end) };
+            unsafe { std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(ptr, n)) }
         }
     }
 
@@ -182,16 +452,95 @@ mod into_iter {
 
         #[inline]
         fn next(&mut self) -> Option<Self::Item> {
-            if self.ptr == self.end {
+            if self.start == self.end {
                 None
             } else {
                 unsafe {
-                    let p = self.ptr;
-                    self.ptr = self.ptr.add(1);
+                    let p = self.ptr().add(self.start);
+                    self.start += 1;
                     Some(std::ptr::read(p))
                 }
             }
         }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let len = self.end - self.start;
+            (len, Some(len))
+        }
+
+        #[inline]
+        fn count(self) -> usize {
+            self.len()
+        }
+
+        /// Returns the `n`th element, dropping all the skipped ones.
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            self.drop_front(n.min(self.len()));
+            self.next()
+        }
+
+        #[inline]
+        fn last(mut self) -> Option<Self::Item> {
+            self.next_back()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.start == self.end {
+                None
+            } else {
+                unsafe {
+                    self.end -= 1;
+                    Some(std::ptr::read(self.ptr().add(self.end)))
+                }
+            }
+        }
+
+        /// Returns the `n`th element from the end, dropping all the skipped ones.
+        #[inline]
+        fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+            self.drop_back(n.min(self.len()));
+            self.next_back()
+        }
     }
-}
 
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+
+    impl<T> FusedIterator for IntoIter<T> {}
+
+
+    impl<T: Clone> Clone for IntoIter<T> {
+
+        /// Clones the remaining elements into a new array and iterates over it.
+        #[inline]
+        fn clone(&self) -> Self {
+            IntoIter::new(Array::from_vec_buffer(self.as_slice().to_vec()))
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+        }
+    }
+
+
+    impl<T> Drop for IntoIter<T> {
+
+        /// Drops the elements, which were not yielded yet.
+        /// The memory is freed by the inner array after that.
+        fn drop(&mut self) {
+            self.drop_front(self.len());
+        }
+    }
+}
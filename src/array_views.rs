@@ -0,0 +1,1297 @@
+//! Provides borrowed, possibly strided views into an [`Array`](crate::Array)
+
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+pub use view::ArrayView;
+pub use view_mut::ArrayViewMut;
+pub use iter::{ViewIter, ViewIterMut};
+pub use lanes::{Lanes, LanesMut};
+
+
+/// Positions of the elements of a view in the underlying array:
+/// the `i`-th element of the view is the `offset + i * stride`-th element of the array.
+///
+/// Every position of the elements `0..len` is in bounds of the array.
+/// The offset of an empty view means nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Strided {
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) stride: isize,
+}
+
+
+impl Strided {
+
+    /// All the elements of an array of size `len` in order.
+    #[inline]
+    pub(crate) fn contiguous(len: usize) -> Self {
+        Strided { offset: 0, len, stride: 1 }
+    }
+
+    /// Returns the position of the `i`-th element in the array, `i` must be less than `len`.
+    #[inline]
+    pub(crate) fn position(&self, i: usize) -> usize {
+        // wrapping arithmetic gives the right position even if `i * stride` exceeds `isize`
+        // (possible only for zero-sized types)
+        self.offset.wrapping_add((i as isize).wrapping_mul(self.stride) as usize)
+    }
+
+    /// Moves all the positions by `n * step`.
+    #[inline]
+    pub(crate) fn shifted(self, n: usize, step: isize) -> Self {
+        let offset = self.offset.wrapping_add((n as isize).wrapping_mul(step) as usize);
+        Strided { offset, ..self }
+    }
+
+    /// Returns `true`, if the elements follow each other in memory in order.
+    #[inline]
+    pub(crate) fn is_contiguous(&self) -> bool {
+        self.stride == 1 || self.len <= 1
+    }
+
+    /// The `len` elements, starting from the `start`-th one, with the given stride.
+    #[inline]
+    fn sub(self, start: usize, len: usize, stride: isize) -> Self {
+        let offset = if len == 0 { self.offset } else { self.position(start) };
+        Strided { offset, len, stride }
+    }
+
+    #[track_caller]
+    pub(crate) fn slice<R: RangeBounds<usize>>(self, range: R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end && end <= self.len,
+            "range {start}..{end} is out of bounds of the size {}", self.len
+        );
+        self.sub(start, end - start, self.stride)
+    }
+
+    #[track_caller]
+    pub(crate) fn step_by(self, step: isize) -> Self {
+        assert!(step != 0, "step must not be zero");
+        let this = if step < 0 { self.rev() } else { self };
+        let step = step.unsigned_abs();
+        let len = if this.len == 0 { 0 } else { (this.len - 1) / step + 1 };
+        // the stride can overflow only if the view has less than two elements
+        this.sub(0, len, this.stride.saturating_mul(step as isize))
+    }
+
+    #[inline]
+    pub(crate) fn rev(self) -> Self {
+        let last = self.len.saturating_sub(1);
+        self.sub(last, self.len, self.stride.saturating_neg())
+    }
+
+    #[track_caller]
+    pub(crate) fn split_at(self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len, "split index {mid} is out of bounds of the size {}", self.len);
+        (self.sub(0, mid, self.stride), self.sub(mid, self.len - mid, self.stride))
+    }
+
+    #[track_caller]
+    pub(crate) fn check_index(&self, index: usize) {
+        assert!(index < self.len, "index out of bounds: the len is {} but the index is {index}", self.len);
+    }
+}
+
+
+/// Formats the items of a cloneable iterator as a list.
+pub(crate) struct Entries<I>(pub(crate) I);
+
+impl<I> fmt::Debug for Entries<I>
+    where
+        I: Iterator + Clone,
+        I::Item: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+
+mod view {
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ops::RangeBounds;
+    use std::ptr::NonNull;
+    use crate::array::Array;
+    use super::{Entries, Strided, ViewIter};
+
+    /// Immutable, possibly strided view of the elements of an [`Array`](Array).
+    ///
+    /// The `i`-th element of the view is the
+    /// [`offset`](ArrayView::offset)` + i * `[`stride`](ArrayView::stride)-th element of the array,
+    /// so the view may skip elements or go backwards without copying them.
+    ///
+    /// Borrows the array like `&'a [T]`: any number of views,
+    /// overlapping or not, may exist at the same time.
+    ///
+    /// This `struct` is created by the [`view`] method on `Array`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// // interleaved stereo samples
+    /// let samples: Array<i32> = vec![1, -1, 2, -2, 3, -3].into();
+    /// let left = samples.view().step_by(2);
+    /// let right = samples.view().slice(1..).step_by(2);
+    /// assert_eq!(left, [1, 2, 3]);
+    /// assert_eq!(right.rev(), [-3, -2, -1]);
+    /// ```
+    ///
+    /// [`view`]: Array::view
+    pub struct ArrayView<'a, T> {
+        // the view must not outlive the borrow of the array
+        marker: PhantomData<&'a T>,
+        base: NonNull<T>,
+        strided: Strided,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for ArrayView<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ArrayView<'_, T> {}
+
+
+    impl<'a, T> ArrayView<'a, T> {
+
+        /// # Safety
+        ///
+        /// The elements of `strided` must be positions of initialized elements at `base`,
+        /// which are borrowed immutably for `'a`.
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: Strided) -> Self {
+            Self { marker: PhantomData, base, strided }
+        }
+
+        /// Returns the number of elements in the view.
+        #[inline]
+        pub fn size(&self) -> usize {
+            self.strided.len
+        }
+
+        /// Returns `true`, if the view has no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.strided.len == 0
+        }
+
+        /// Returns the index of the first element of the view in the array.
+        ///
+        /// Means nothing for empty views.
+        #[inline]
+        pub fn offset(&self) -> usize {
+            self.strided.offset
+        }
+
+        /// Returns the distance between the neighbouring elements of the view in the array,
+        /// measured in elements. Negative for the reversed views.
+        #[inline]
+        pub fn stride(&self) -> isize {
+            self.strided.stride
+        }
+
+        /// Returns reference at an element
+        /// or None if the given index is out of bounds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = vec![1, 2, 3].into();
+        /// let view = arr.view().rev();
+        /// assert_eq!(view.try_get(0), Some(&3));
+        /// assert_eq!(view.try_get(3), None);
+        /// ```
+        #[inline]
+        pub fn try_get(&self, index: usize) -> Option<&'a T> {
+            if index < self.strided.len {
+                unsafe { Some(&*self.base.as_ptr().add(self.strided.position(index))) }
+            } else {
+                None
+            }
+        }
+
+        /// Returns an iterator over the elements of the view.
+        #[inline]
+        pub fn iter(&self) -> ViewIter<'a, T> {
+            unsafe { ViewIter::new(self.base, self.strided) }
+        }
+
+        /// Returns the view of the elements in the given `range` of this view.
+        ///
+        /// # Panics
+        ///
+        /// if the `range` is out of bounds of the view.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = (0..10).collect();
+        /// assert_eq!(arr.view().slice(2..5), [2, 3, 4]);
+        /// assert_eq!(arr.view().rev().slice(..=2), [9, 8, 7]);
+        /// ```
+        #[inline]
+        #[track_caller]
+        pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+            unsafe { Self::new(self.base, self.strided.slice(range)) }
+        }
+
+        /// Returns the view of every `step`-th element of this view, starting from the first one.
+        ///
+        /// A negative `step` goes backwards, starting from the last element.
+        ///
+        /// # Panics
+        ///
+        /// if `step` is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = (0..7).collect();
+        /// assert_eq!(arr.view().step_by(3), [0, 3, 6]);
+        /// assert_eq!(arr.view().step_by(-2), [6, 4, 2, 0]);
+        /// ```
+        #[inline]
+        #[track_caller]
+        pub fn step_by(&self, step: isize) -> Self {
+            unsafe { Self::new(self.base, self.strided.step_by(step)) }
+        }
+
+        /// Returns the view of the elements of this view in the reverse order.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = vec![1, 2, 3].into();
+        /// assert_eq!(arr.view().rev(), [3, 2, 1]);
+        /// ```
+        #[inline]
+        pub fn rev(&self) -> Self {
+            unsafe { Self::new(self.base, self.strided.rev()) }
+        }
+
+        /// Divides the view into two at the index `mid`:
+        /// the first one contains the elements `[0, mid)`, the second one `[mid, size)`.
+        ///
+        /// # Panics
+        ///
+        /// if `mid > size`.
+        #[inline]
+        #[track_caller]
+        pub fn split_at(&self, mid: usize) -> (Self, Self) {
+            let (left, right) = self.strided.split_at(mid);
+            unsafe { (Self::new(self.base, left), Self::new(self.base, right)) }
+        }
+
+        /// Returns the elements of the view as a slice,
+        /// if they follow each other in memory in order.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = vec![1, 2, 3].into();
+        /// assert_eq!(arr.view().slice(1..).as_slice(), Some(&[2, 3][..]));
+        /// assert_eq!(arr.view().rev().as_slice(), None);
+        /// ```
+        #[inline]
+        pub fn as_slice(&self) -> Option<&'a [T]> {
+            if !self.strided.is_contiguous() {
+                return None;
+            }
+            unsafe {
+                let first = self.base.as_ptr().wrapping_add(self.strided.offset);
+                Some(std::slice::from_raw_parts(first, self.strided.len))
+            }
+        }
+
+        /// Copies the elements of the view into a new array.
+        ///
+        /// # Panics
+        ///
+        /// if failed allocating memory for the array or `T::clone` panicked.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let arr: Array<i32> = vec![1, 2, 3].into();
+        /// let reversed: Array<i32> = arr.view().rev().to_array();
+        /// assert_eq!(reversed, [3, 2, 1]);
+        /// ```
+        pub fn to_array(&self) -> Array<T>
+            where T: Clone
+        {
+            Array::from_exact_iter(self.iter().cloned())
+        }
+    }
+
+
+    impl<T> Clone for ArrayView<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+
+    impl<T> Copy for ArrayView<'_, T> {}
+
+
+    impl<T> std::ops::Index<usize> for ArrayView<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: usize) -> &T {
+            self.strided.check_index(index);
+            unsafe { &*self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for ArrayView<'a, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIter<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &ArrayView<'a, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIter<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<T, U> PartialEq<ArrayView<'_, U>> for ArrayView<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &ArrayView<'_, U>) -> bool {
+            self.size() == other.size() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        }
+    }
+
+
+    impl<T, U> PartialEq<[U]> for ArrayView<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &[U]) -> bool {
+            self.size() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+        }
+    }
+
+
+    impl<T, U, const N: usize> PartialEq<[U; N]> for ArrayView<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &[U; N]) -> bool {
+            *self == other[..]
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ArrayView<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ArrayView").field(&Entries(self.iter())).finish()
+        }
+    }
+}
+
+
+mod view_mut {
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ops::RangeBounds;
+    use std::ptr::NonNull;
+    use crate::array::Array;
+    use super::{ArrayView, Entries, Strided, ViewIter, ViewIterMut};
+
+    /// Mutable, possibly strided view of the elements of an [`Array`](Array).
+    ///
+    /// The `i`-th element of the view is the
+    /// [`offset`](ArrayViewMut::offset)` + i * `[`stride`](ArrayViewMut::stride)-th element of the array,
+    /// so the view may skip elements or go backwards without copying them.
+    ///
+    /// Borrows the array mutably, like `&'a mut [T]`, so the views,
+    /// which exist at the same time, never overlap:
+    /// they can only be made by splitting one view into disjoint parts.
+    ///
+    /// ```compile_fail
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut array: Array<i32> = vec![1, 2, 3, 4].into();
+    /// let mut even = array.view_mut().step_by(2);
+    /// let odd = array.view_mut().slice(1..).step_by(2);
+    /// even[0] = odd[0];
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut array: Array<i32> = vec![1, 2, 3, 4].into();
+    /// let mut view = array.view_mut().rev();
+    /// view[0] = array[0];
+    /// ```
+    ///
+    /// This `struct` is created by the [`view_mut`] and [`split_at_mut_array`] methods on `Array`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut array: Array<i32> = vec![1, 2, 3, 4].into();
+    /// let (mut left, mut right) = array.split_at_mut_array(2);
+    /// std::mem::swap(&mut left[0], &mut right[1]);
+    /// assert_eq!(array, [4, 2, 3, 1]);
+    ///
+    /// for x in array.view_mut().step_by(2) {
+    ///     *x = 0;
+    /// }
+    /// assert_eq!(array, [0, 2, 0, 1]);
+    /// ```
+    ///
+    /// [`view_mut`]: Array::view_mut
+    /// [`split_at_mut_array`]: Array::split_at_mut_array
+    pub struct ArrayViewMut<'a, T> {
+        // the view must not outlive the mutable borrow of the array
+        marker: PhantomData<&'a mut T>,
+        base: NonNull<T>,
+        strided: Strided,
+    }
+
+
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for ArrayViewMut<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ArrayViewMut<'_, T> {}
+
+
+    impl<'a, T> ArrayViewMut<'a, T> {
+
+        /// # Safety
+        ///
+        /// The elements of `strided` must be distinct positions of initialized elements at `base`,
+        /// which are borrowed mutably for `'a` and not accessed by anything else during it.
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: Strided) -> Self {
+            Self { marker: PhantomData, base, strided }
+        }
+
+        /// Returns the number of elements in the view.
+        #[inline]
+        pub fn size(&self) -> usize {
+            self.strided.len
+        }
+
+        /// Returns `true`, if the view has no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.strided.len == 0
+        }
+
+        /// Returns the index of the first element of the view in the array.
+        ///
+        /// Means nothing for empty views.
+        #[inline]
+        pub fn offset(&self) -> usize {
+            self.strided.offset
+        }
+
+        /// Returns the distance between the neighbouring elements of the view in the array,
+        /// measured in elements. Negative for the reversed views.
+        #[inline]
+        pub fn stride(&self) -> isize {
+            self.strided.stride
+        }
+
+        /// Returns immutable reference at an element
+        /// or None if the given index is out of bounds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array: Array<i32> = vec![1, 2, 3].into();
+        /// let (_, right) = array.split_at_mut_array(1);
+        /// assert_eq!(right.try_get(0), Some(&2));
+        /// assert_eq!(right.try_get(2), None);
+        /// ```
+        #[inline]
+        pub fn try_get(&self, index: usize) -> Option<&T> {
+            self.view().try_get(index)
+        }
+
+        /// Returns mutable reference at an element
+        /// or None if the given index is out of bounds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array: Array<i32> = vec![1, 2, 3].into();
+        /// let (_, mut right) = array.split_at_mut_array(1);
+        /// *right.try_get_mut(1).unwrap() = 5;
+        /// assert_eq!(right.try_get_mut(2), None);
+        /// assert_eq!(array, [1, 2, 5]);
+        /// ```
+        #[inline]
+        pub fn try_get_mut(&mut self, index: usize) -> Option<&mut T> {
+            if index < self.strided.len {
+                unsafe { Some(&mut *self.base.as_ptr().add(self.strided.position(index))) }
+            } else {
+                None
+            }
+        }
+
+        /// Returns an iterator over the elements of the view.
+        #[inline]
+        pub fn iter(&self) -> ViewIter<'_, T> {
+            unsafe { ViewIter::new(self.base, self.strided) }
+        }
+
+        /// Returns an iterator, that allows modifying each element of the view.
+        #[inline]
+        pub fn iter_mut(&mut self) -> ViewIterMut<'_, T> {
+            unsafe { ViewIterMut::new(self.base, self.strided) }
+        }
+
+        /// Returns an immutable view of the same elements, borrowing this one.
+        #[inline]
+        pub fn view(&self) -> ArrayView<'_, T> {
+            unsafe { ArrayView::new(self.base, self.strided) }
+        }
+
+        /// Returns a mutable view of the same elements, borrowing this one,
+        /// so this view can be used again afterwards.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array: Array<i32> = vec![1, 2, 3, 4].into();
+        /// let mut view = array.view_mut();
+        /// view.reborrow().step_by(2).iter_mut().for_each(|x| *x = 0);
+        /// view.reborrow().rev().slice(..1).iter_mut().for_each(|x| *x = 9);
+        /// assert_eq!(array, [0, 2, 0, 9]);
+        /// ```
+        #[inline]
+        pub fn reborrow(&mut self) -> ArrayViewMut<'_, T> {
+            unsafe { ArrayViewMut::new(self.base, self.strided) }
+        }
+
+        /// Converts the view into the view of the elements in the given `range` of it.
+        ///
+        /// # Panics
+        ///
+        /// if the `range` is out of bounds of the view.
+        #[inline]
+        #[track_caller]
+        pub fn slice<R: RangeBounds<usize>>(self, range: R) -> Self {
+            unsafe { Self::new(self.base, self.strided.slice(range)) }
+        }
+
+        /// Converts the view into the view of its every `step`-th element,
+        /// starting from the first one.
+        ///
+        /// A negative `step` goes backwards, starting from the last element.
+        ///
+        /// # Panics
+        ///
+        /// if `step` is zero.
+        #[inline]
+        #[track_caller]
+        pub fn step_by(self, step: isize) -> Self {
+            unsafe { Self::new(self.base, self.strided.step_by(step)) }
+        }
+
+        /// Converts the view into the view of its elements in the reverse order.
+        #[inline]
+        pub fn rev(self) -> Self {
+            unsafe { Self::new(self.base, self.strided.rev()) }
+        }
+
+        /// Divides the view into two at the index `mid`:
+        /// the first one contains the elements `[0, mid)`, the second one `[mid, size)`.
+        ///
+        /// # Panics
+        ///
+        /// if `mid > size`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::Array;
+        ///
+        /// let mut array: Array<i32> = vec![1, 2, 3, 4, 5].into();
+        /// let (mut first, mut rest) = array.view_mut().rev().split_at(2);
+        /// std::mem::swap(&mut first[0], &mut rest[0]);
+        /// assert_eq!(array, [1, 2, 5, 4, 3]);
+        /// ```
+        #[inline]
+        #[track_caller]
+        pub fn split_at(self, mid: usize) -> (Self, Self) {
+            let (left, right) = self.strided.split_at(mid);
+            // the halves have no common elements
+            unsafe { (Self::new(self.base, left), Self::new(self.base, right)) }
+        }
+
+        /// Returns the elements of the view as a slice,
+        /// if they follow each other in memory in order.
+        #[inline]
+        pub fn as_slice(&self) -> Option<&[T]> {
+            self.view().as_slice()
+        }
+
+        /// Returns the elements of the view as a mutable slice,
+        /// if they follow each other in memory in order.
+        #[inline]
+        pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+            self.reborrow().into_slice()
+        }
+
+        /// Converts the view into a mutable slice with the same lifetime,
+        /// if the elements follow each other in memory in order.
+        #[inline]
+        pub fn into_slice(self) -> Option<&'a mut [T]> {
+            if !self.strided.is_contiguous() {
+                return None;
+            }
+            unsafe {
+                let first = self.base.as_ptr().wrapping_add(self.strided.offset);
+                Some(std::slice::from_raw_parts_mut(first, self.strided.len))
+            }
+        }
+
+        /// Copies the elements of the view into a new array.
+        ///
+        /// # Panics
+        ///
+        /// if failed allocating memory for the array or `T::clone` panicked.
+        #[inline]
+        pub fn to_array(&self) -> Array<T>
+            where T: Clone
+        {
+            self.view().to_array()
+        }
+    }
+
+
+    impl<T> std::ops::Index<usize> for ArrayViewMut<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: usize) -> &T {
+            self.strided.check_index(index);
+            unsafe { &*self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<T> std::ops::IndexMut<usize> for ArrayViewMut<'_, T> {
+
+        #[inline]
+        #[track_caller]
+        fn index_mut(&mut self, index: usize) -> &mut T {
+            self.strided.check_index(index);
+            unsafe { &mut *self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for ArrayViewMut<'a, T> {
+        type Item = &'a mut T;
+        type IntoIter = ViewIterMut<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            unsafe { ViewIterMut::new(self.base, self.strided) }
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &'a ArrayViewMut<'_, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIter<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &'a mut ArrayViewMut<'_, T> {
+        type Item = &'a mut T;
+        type IntoIter = ViewIterMut<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
+    impl<T, U> PartialEq<ArrayViewMut<'_, U>> for ArrayViewMut<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &ArrayViewMut<'_, U>) -> bool {
+            self.view() == other.view()
+        }
+    }
+
+
+    impl<T, U> PartialEq<[U]> for ArrayViewMut<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &[U]) -> bool {
+            self.view() == *other
+        }
+    }
+
+
+    impl<T, U, const N: usize> PartialEq<[U; N]> for ArrayViewMut<'_, T>
+        where T: PartialEq<U>
+    {
+        #[inline]
+        fn eq(&self, other: &[U; N]) -> bool {
+            self.view() == *other
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ArrayViewMut<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ArrayViewMut").field(&Entries(self.iter())).finish()
+        }
+    }
+}
+
+
+mod iter {
+
+    use std::fmt;
+    use std::iter::FusedIterator;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use super::{Entries, Strided};
+
+    /// Immutable iterator over the elements of a view.
+    ///
+    /// This `struct` is created by the `iter` methods
+    /// on [`ArrayView`](super::ArrayView) and [`ArrayViewMut`](super::ArrayViewMut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let array: Array<i32> = vec![1, 2, 3, 4].into();
+    /// let odd: Vec<i32> = array.view().step_by(2).iter().copied().collect();
+    /// assert_eq!(odd, [1, 3]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct ViewIter<'a, T> {
+        marker: PhantomData<&'a T>,
+        // the next element from the front, if any are left
+        ptr: *const T,
+        stride: isize,
+        len: usize,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for ViewIter<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ViewIter<'_, T> {}
+
+
+    impl<'a, T> ViewIter<'a, T> {
+
+        /// # Safety
+        ///
+        /// The same as for [`ArrayView::new`](super::ArrayView::new).
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: Strided) -> Self {
+            Self {
+                marker: PhantomData,
+                // the offset of an empty view may be out of bounds
+                ptr: base.as_ptr().wrapping_add(strided.offset),
+                stride: strided.stride,
+                len: strided.len,
+            }
+        }
+    }
+
+
+    impl<'a, T> Iterator for ViewIter<'a, T> {
+        type Item = &'a T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                let p = self.ptr;
+                // the pointer leaves the array after the last element
+                self.ptr = self.ptr.wrapping_offset(self.stride);
+                self.len -= 1;
+                unsafe { Some(&*p) }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+
+        #[inline]
+        fn count(self) -> usize {
+            self.len
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if n >= self.len {
+                self.len = 0;
+                None
+            } else {
+                self.ptr = self.ptr.wrapping_offset(self.stride.wrapping_mul(n as isize));
+                self.len -= n;
+                self.next()
+            }
+        }
+
+        #[inline]
+        fn last(mut self) -> Option<Self::Item> {
+            self.next_back()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for ViewIter<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                unsafe { Some(&*self.ptr.wrapping_offset(self.stride.wrapping_mul(self.len as isize))) }
+            }
+        }
+
+        #[inline]
+        fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+            self.len -= n.min(self.len);
+            self.next_back()
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for ViewIter<'_, T> {}
+
+
+    impl<T> FusedIterator for ViewIter<'_, T> {}
+
+
+    impl<T> Clone for ViewIter<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            Self { marker: PhantomData, ptr: self.ptr, stride: self.stride, len: self.len }
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ViewIter<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ViewIter").field(&Entries(self.clone())).finish()
+        }
+    }
+
+
+    /// Mutable iterator over the elements of a view.
+    ///
+    /// This `struct` is created by the [`iter_mut`](super::ArrayViewMut::iter_mut) method
+    /// on [`ArrayViewMut`](super::ArrayViewMut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array;
+    ///
+    /// let mut array: Array<i32> = vec![1, 2, 3, 4].into();
+    /// for x in array.view_mut().rev().step_by(2).iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(array, [1, 20, 3, 40]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct ViewIterMut<'a, T> {
+        marker: PhantomData<&'a mut T>,
+        // the next element from the front, if any are left
+        ptr: *mut T,
+        stride: isize,
+        len: usize,
+    }
+
+
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for ViewIterMut<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ViewIterMut<'_, T> {}
+
+
+    impl<'a, T> ViewIterMut<'a, T> {
+
+        /// # Safety
+        ///
+        /// The same as for [`ArrayViewMut::new`](super::ArrayViewMut::new).
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: Strided) -> Self {
+            Self {
+                marker: PhantomData,
+                // the offset of an empty view may be out of bounds
+                ptr: base.as_ptr().wrapping_add(strided.offset),
+                stride: strided.stride,
+                len: strided.len,
+            }
+        }
+    }
+
+
+    impl<'a, T> Iterator for ViewIterMut<'a, T> {
+        type Item = &'a mut T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                let p = self.ptr;
+                // the pointer leaves the array after the last element
+                self.ptr = self.ptr.wrapping_offset(self.stride);
+                self.len -= 1;
+                unsafe { Some(&mut *p) }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+
+        #[inline]
+        fn count(self) -> usize {
+            self.len
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if n >= self.len {
+                self.len = 0;
+                None
+            } else {
+                self.ptr = self.ptr.wrapping_offset(self.stride.wrapping_mul(n as isize));
+                self.len -= n;
+                self.next()
+            }
+        }
+
+        #[inline]
+        fn last(mut self) -> Option<Self::Item> {
+            self.next_back()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for ViewIterMut<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                unsafe { Some(&mut *self.ptr.wrapping_offset(self.stride.wrapping_mul(self.len as isize))) }
+            }
+        }
+
+        #[inline]
+        fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+            self.len -= n.min(self.len);
+            self.next_back()
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for ViewIterMut<'_, T> {}
+
+
+    impl<T> FusedIterator for ViewIterMut<'_, T> {}
+
+
+    impl<T: fmt::Debug> fmt::Debug for ViewIterMut<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let remaining = ViewIter { marker: PhantomData, ptr: self.ptr, stride: self.stride, len: self.len };
+            f.debug_tuple("ViewIterMut").field(&Entries(remaining)).finish()
+        }
+    }
+}
+
+
+mod lanes {
+
+    use std::iter::FusedIterator;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use super::{ArrayView, ArrayViewMut, Strided};
+
+    /// Iterator over equally spaced views of an array, like the rows or the columns of a matrix.
+    ///
+    /// This `struct` is created by the [`iter_rows`](crate::Array2::iter_rows)
+    /// and [`iter_cols`](crate::Array2::iter_cols) methods on [`Array2`](crate::Array2).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let matrix = Array2::from_fn(2, 3, |i, j| 10 * i + j);
+    /// let sums: Vec<usize> = matrix.iter_rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 33]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct Lanes<'a, T> {
+        marker: PhantomData<&'a T>,
+        base: NonNull<T>,
+        // the next lane from the front, if any are left
+        lane: Strided,
+        // the distance between the offsets of the neighbouring lanes
+        step: isize,
+        count: usize,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for Lanes<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for Lanes<'_, T> {}
+
+
+    impl<'a, T> Lanes<'a, T> {
+
+        /// # Safety
+        ///
+        /// The `count` lanes, `lane` shifted by `0..count` steps,
+        /// must satisfy the requirements of [`ArrayView::new`].
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, lane: Strided, step: isize, count: usize) -> Self {
+            Self { marker: PhantomData, base, lane, step, count }
+        }
+    }
+
+
+    impl<'a, T> Iterator for Lanes<'a, T> {
+        type Item = ArrayView<'a, T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.count == 0 {
+                return None;
+            }
+            let lane = self.lane;
+            self.lane = lane.shifted(1, self.step);
+            self.count -= 1;
+            unsafe { Some(ArrayView::new(self.base, lane)) }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.count, Some(self.count))
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            let n = n.min(self.count);
+            self.lane = self.lane.shifted(n, self.step);
+            self.count -= n;
+            self.next()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for Lanes<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.count == 0 {
+                return None;
+            }
+            self.count -= 1;
+            unsafe { Some(ArrayView::new(self.base, self.lane.shifted(self.count, self.step))) }
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for Lanes<'_, T> {}
+
+
+    impl<T> FusedIterator for Lanes<'_, T> {}
+
+
+    impl<T> Clone for Lanes<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            Self { ..*self }
+        }
+    }
+
+
+    /// Iterator over equally spaced mutable views of an array,
+    /// like the rows or the columns of a matrix.
+    ///
+    /// This `struct` is created by the [`iter_rows_mut`](crate::Array2::iter_rows_mut)
+    /// and [`iter_cols_mut`](crate::Array2::iter_cols_mut) methods on [`Array2`](crate::Array2).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::Array2;
+    ///
+    /// let mut matrix = Array2::from_fn(2, 2, |i, j| i + j);
+    /// for (i, row) in matrix.iter_rows_mut().enumerate() {
+    ///     row.into_iter().for_each(|x| *x *= i + 1);
+    /// }
+    /// assert_eq!(matrix.as_slice(), &[0, 1, 2, 4]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct LanesMut<'a, T> {
+        marker: PhantomData<&'a mut T>,
+        base: NonNull<T>,
+        // the next lane from the front, if any are left
+        lane: Strided,
+        // the distance between the offsets of the neighbouring lanes
+        step: isize,
+        count: usize,
+    }
+
+
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for LanesMut<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for LanesMut<'_, T> {}
+
+
+    impl<'a, T> LanesMut<'a, T> {
+
+        /// # Safety
+        ///
+        /// The `count` lanes, `lane` shifted by `0..count` steps,
+        /// must satisfy the requirements of [`ArrayViewMut::new`] and have no common elements.
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, lane: Strided, step: isize, count: usize) -> Self {
+            Self { marker: PhantomData, base, lane, step, count }
+        }
+    }
+
+
+    impl<'a, T> Iterator for LanesMut<'a, T> {
+        type Item = ArrayViewMut<'a, T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.count == 0 {
+                return None;
+            }
+            let lane = self.lane;
+            self.lane = lane.shifted(1, self.step);
+            self.count -= 1;
+            // every lane is yielded once, so the views never overlap
+            unsafe { Some(ArrayViewMut::new(self.base, lane)) }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.count, Some(self.count))
+        }
+
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            let n = n.min(self.count);
+            self.lane = self.lane.shifted(n, self.step);
+            self.count -= n;
+            self.next()
+        }
+    }
+
+
+    impl<T> DoubleEndedIterator for LanesMut<'_, T> {
+
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.count == 0 {
+                return None;
+            }
+            self.count -= 1;
+            unsafe { Some(ArrayViewMut::new(self.base, self.lane.shifted(self.count, self.step))) }
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for LanesMut<'_, T> {}
+
+
+    impl<T> FusedIterator for LanesMut<'_, T> {}
+}
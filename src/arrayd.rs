@@ -0,0 +1,557 @@
+//! Provides the [`ArrayD`] with any number of dimensions, determined at run time
+
+use std::fmt;
+
+use super::{Array, Array2, ArrayError, ArrayViewD, ArrayViewMutD, AxisSlice, IndexedIter, Order, Zeroable};
+use super::arrayd_views::{Cursor, Nested, StridedD};
+
+
+/// Multi-dimensional array, whose number of dimensions and their lengths are determined at run time.
+///
+/// The elements are stored in one [`Array`] in the row-major (C) order,
+/// so the conversions to and from the flat array do not copy.
+/// The [`permute_axes`](ArrayD::permute_axes), [`transpose`](ArrayD::transpose)
+/// and [`index_axis`](ArrayD::index_axis) methods give strided views without copying either.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::ArrayD;
+///
+/// // the rank comes from a config file
+/// let shape = vec![2, 3, 4];
+/// let mut grid: ArrayD<f64> = ArrayD::new(&shape).unwrap();
+/// assert_eq!((grid.ndim(), grid.size()), (3, 24));
+///
+/// grid[[1, 2, 3]] = 1.0;
+/// assert_eq!(grid[&[1, 2, 3][..]], 1.0);
+/// assert_eq!(grid.as_slice()[23], 1.0);
+/// ```
+pub struct ArrayD<T> {
+    data: Array<T>,
+    // the shape and the row-major strides, the offset is zero
+    strided: StridedD,
+}
+
+
+/// Returns the number of elements of the given shape.
+pub(crate) fn shape_size(shape: &[usize]) -> Result<usize, ArrayError> {
+    // an empty axis makes the whole array empty, whatever the other axes are
+    if shape.contains(&0) {
+        return Ok(0);
+    }
+    shape.iter().try_fold(1usize, |size, &len| size.checked_mul(len)).ok_or(ArrayError::ShapeOverflow)
+}
+
+
+impl<T> ArrayD<T> {
+
+    /// Creates an array of the given shape, filled with zeros.
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * the number of elements overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * failed creating a [`layout`] with the following size,
+    /// * failed [allocating] memory for the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr: ArrayD<i32> = ArrayD::new(&[2, 2, 2]).unwrap();
+    /// assert_eq!(arr[[1, 1, 1]], 0);
+    /// ```
+    ///
+    /// [allocating]: std::alloc
+    /// [`layout`]: std::alloc::Layout
+    #[inline]
+    pub fn new(shape: &[usize]) -> Result<Self, ArrayError>
+        where T: Zeroable
+    {
+        let data = Array::new(shape_size(shape)?)?;
+        Ok(ArrayD::from_parts(data, shape.into()))
+    }
+
+    /// Creates an array of the given shape, where each element is `f(index)`.
+    ///
+    /// The elements are made in the row-major order.
+    /// If `f` panics, the elements, which were already made, are dropped.
+    ///
+    /// # Panics
+    ///
+    /// if the number of elements overflows, failed allocating memory for the array or `f` panicked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 10 + index[1]);
+    /// assert_eq!(arr.as_slice(), &[0, 1, 2, 10, 11, 12]);
+    /// ```
+    pub fn from_fn<F>(shape: &[usize], mut f: F) -> Self
+        where F: FnMut(&[usize]) -> T
+    {
+        let size = shape_size(shape).expect("failed to create new ArrayD");
+        let mut cursor = Cursor::new(StridedD::contiguous(shape));
+        let data = Array::from_fn(size, |_| {
+            let value = f(cursor.index());
+            cursor.next_position();
+            value
+        });
+        ArrayD::from_parts(data, shape.into())
+    }
+
+    /// Reinterprets the flat array as an array of the given shape without copying.
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * the number of elements overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * the number of elements differs from the size of the array
+    ///   ([`LengthMismatch`](ArrayError::LengthMismatch)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{Array, ArrayD};
+    ///
+    /// let flat: Array<i32> = (0..8).collect();
+    /// let cube = ArrayD::from_array(flat, &[2, 2, 2]).unwrap();
+    /// assert_eq!(cube[[1, 0, 1]], 5);
+    /// ```
+    pub fn from_array(data: Array<T>, shape: &[usize]) -> Result<Self, ArrayError> {
+        let size = shape_size(shape)?;
+        if size != data.size() {
+            return Err(ArrayError::LengthMismatch { expected: size, found: data.size() });
+        }
+        Ok(ArrayD::from_parts(data, shape.into()))
+    }
+
+    /// The size of `data` must be the number of elements of `shape`.
+    #[inline]
+    pub(crate) fn from_parts(data: Array<T>, shape: Array<usize>) -> Self {
+        let strides = StridedD::row_major_strides(&shape);
+        ArrayD { data, strided: StridedD { offset: 0, shape, strides } }
+    }
+
+    /// Converts the array into the flat array of its elements in the row-major order without copying.
+    #[inline]
+    pub fn into_array(self) -> Array<T> {
+        self.data
+    }
+
+    /// Converts the array back into the flat array of its elements in the row-major order
+    /// and its shape without copying.
+    ///
+    /// The inverse of [`from_array`](ArrayD::from_array).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[3, 1], |index| index[0]);
+    /// let (data, shape) = arr.into_shape();
+    /// assert_eq!(data, [0, 1, 2]);
+    /// assert_eq!(shape, [3, 1]);
+    /// ```
+    #[inline]
+    pub fn into_shape(self) -> (Array<T>, Array<usize>) {
+        (self.data, self.strided.shape)
+    }
+
+    /// Returns the lengths of the axes.
+    #[inline]
+    pub fn shape(&self) -> &[usize] {
+        &self.strided.shape
+    }
+
+    /// Returns the distances between the neighbouring elements along each axis,
+    /// measured in elements.
+    #[inline]
+    pub fn strides(&self) -> &[isize] {
+        &self.strided.strides
+    }
+
+    /// Returns the number of dimensions.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.strided.ndim()
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.size()
+    }
+
+    /// Returns the elements in the row-major order as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the elements in the row-major order as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Changes the shape of the array without moving the elements.
+    ///
+    /// Returns `ArrayError` and leaves the array as it is, if any of the following cases happened:
+    /// * the number of elements overflows ([`ShapeOverflow`](ArrayError::ShapeOverflow)),
+    /// * the number of elements of the new shape differs from the size of the array
+    ///   ([`LengthMismatch`](ArrayError::LengthMismatch)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{ArrayD, ArrayError};
+    ///
+    /// let mut arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+    /// arr.reshape(&[3, 2]).unwrap();
+    /// assert_eq!(arr[[2, 0]], 4);
+    ///
+    /// let err = arr.reshape(&[4, 2]).unwrap_err();
+    /// assert_eq!(err, ArrayError::LengthMismatch { expected: 8, found: 6 });
+    /// assert_eq!(arr.shape(), &[3, 2]);
+    /// ```
+    pub fn reshape(&mut self, shape: &[usize]) -> Result<(), ArrayError> {
+        let size = shape_size(shape)?;
+        if size != self.size() {
+            return Err(ArrayError::LengthMismatch { expected: size, found: self.size() });
+        }
+        self.strided = StridedD::contiguous(shape);
+        Ok(())
+    }
+
+    /// Returns immutable reference at an element
+    /// or None if the given index is out of bounds or has a wrong number of dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+    /// assert_eq!(arr.try_get(&[1, 1]), Some(&4));
+    /// assert_eq!(arr.try_get(&[2, 0]), None);
+    /// assert_eq!(arr.try_get(&[1, 1, 0]), None);
+    /// ```
+    #[inline]
+    pub fn try_get(&self, index: &[usize]) -> Option<&T> {
+        if self.strided.contains(index) {
+            self.data.try_get(self.strided.position(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns mutable reference at an element
+    /// or None if the given index is out of bounds or has a wrong number of dimensions.
+    #[inline]
+    pub fn try_get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        if self.strided.contains(index) {
+            let position = self.strided.position(index);
+            self.data.try_get_mut(position)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a view of all the elements.
+    #[inline]
+    pub fn view(&self) -> ArrayViewD<'_, T> {
+        unsafe { ArrayViewD::new(self.data.pointer, self.strided.clone()) }
+    }
+
+    /// Returns a mutable view of all the elements.
+    #[inline]
+    pub fn view_mut(&mut self) -> ArrayViewMutD<'_, T> {
+        unsafe { ArrayViewMutD::new(self.data.pointer, self.strided.clone()) }
+    }
+
+    /// Returns a view with the axes reordered:
+    /// the `k`-th axis of the view is the `axes[k]`-th axis of the array.
+    ///
+    /// # Panics
+    ///
+    /// if `axes` is not a permutation of `0..ndim`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3, 4], |index| index.to_vec());
+    /// let view = arr.permute_axes(&[1, 2, 0]);
+    /// assert_eq!(view.shape(), &[3, 4, 2]);
+    /// assert_eq!(view[[2, 3, 1]], [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn permute_axes(&self, axes: &[usize]) -> ArrayViewD<'_, T> {
+        self.view().permute_axes(axes)
+    }
+
+    /// Returns a view with the axes in the reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+    /// let transposed = arr.transpose();
+    /// assert_eq!(transposed.shape(), &[3, 2]);
+    /// assert_eq!(transposed[[2, 1]], arr[[1, 2]]);
+    /// ```
+    #[inline]
+    pub fn transpose(&self) -> ArrayViewD<'_, T> {
+        self.view().transpose()
+    }
+
+    /// Returns a view of the elements with the given `index` along the `axis`,
+    /// which has one dimension less.
+    ///
+    /// # Panics
+    ///
+    /// if the `axis` or the `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3, 4], |index| index[0] * 100 + index[1] * 10 + index[2]);
+    /// let plane = arr.index_axis(1, 2);
+    /// assert_eq!(plane.shape(), &[2, 4]);
+    /// assert_eq!(plane[[1, 3]], 123);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn index_axis(&self, axis: usize, index: usize) -> ArrayViewD<'_, T> {
+        self.view().index_axis(axis, index)
+    }
+
+    /// Returns a mutable view of the elements with the given `index` along the `axis`,
+    /// which has one dimension less.
+    ///
+    /// # Panics
+    ///
+    /// if the `axis` or the `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn index_axis_mut(&mut self, axis: usize, index: usize) -> ArrayViewMutD<'_, T> {
+        self.view_mut().index_axis(axis, index)
+    }
+
+    /// Returns a view of the elements, selected by `info`: see [`s!`](crate::s).
+    ///
+    /// Returns `ArrayError`, if any of the following cases happened:
+    /// * `info` takes more axes than the array has ([`TooManyAxes`](ArrayError::TooManyAxes)),
+    /// * a range is out of bounds of its axis ([`SliceOutOfBounds`](ArrayError::SliceOutOfBounds)),
+    /// * an index is out of bounds of its axis
+    ///   ([`AxisIndexOutOfBounds`](ArrayError::AxisIndexOutOfBounds)),
+    /// * a step is zero ([`ZeroStep`](ArrayError::ZeroStep)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{s, ArrayD, ArrayError};
+    ///
+    /// let arr = ArrayD::from_fn(&[4, 6], |index| index[0] * 10 + index[1]);
+    /// let block = arr.slice(s![1..3, ..;-3]).unwrap();
+    /// assert_eq!(block.shape(), &[2, 2]);
+    /// assert_eq!(block[[0, 0]], 15);
+    /// assert_eq!(block[[1, 1]], 22);
+    ///
+    /// let err = arr.slice(s![2..5]).unwrap_err();
+    /// assert_eq!(err, ArrayError::SliceOutOfBounds { axis: 0, start: 2, end: 5, len: 4 });
+    /// ```
+    #[inline]
+    pub fn slice(&self, info: &[AxisSlice]) -> Result<ArrayViewD<'_, T>, ArrayError> {
+        self.view().slice(info)
+    }
+
+    /// Returns a mutable view of the elements, selected by `info`: see [`s!`](crate::s).
+    ///
+    /// Returns the same errors as [`slice`](ArrayD::slice).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::{s, ArrayD};
+    ///
+    /// let mut arr: ArrayD<i32> = ArrayD::new(&[3, 3]).unwrap();
+    /// for x in arr.slice_mut(s![..;2, 1]).unwrap() {
+    ///     *x = 1;
+    /// }
+    /// assert_eq!(arr.as_slice(), &[0, 1, 0, 0, 0, 0, 0, 1, 0]);
+    /// ```
+    #[inline]
+    pub fn slice_mut(&mut self, info: &[AxisSlice]) -> Result<ArrayViewMutD<'_, T>, ArrayError> {
+        self.view_mut().slice(info)
+    }
+
+    /// Returns a view of the elements stretched to the `shape` by the broadcasting rules,
+    /// like in NumPy, without copying: see [`ArrayViewD::broadcast_to`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let scale = ArrayD::from_fn(&[2, 1], |index| index[0] + 1);
+    /// let stretched = scale.broadcast_to(&[2, 3]).unwrap();
+    /// assert_eq!(stretched[[1, 2]], 2);
+    /// ```
+    #[inline]
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<ArrayViewD<'_, T>, ArrayError> {
+        self.view().broadcast_to(shape)
+    }
+
+    /// Returns an iterator over the indices and the elements in the row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 2], |index| index[0] + index[1]);
+    /// let diagonal: Vec<i32> = arr.iter_indexed()
+    ///     .filter(|(index, _)| index[0] == index[1])
+    ///     .map(|(_, &x)| x as i32)
+    ///     .collect();
+    /// assert_eq!(diagonal, [0, 2]);
+    /// ```
+    #[inline]
+    pub fn iter_indexed(&self) -> IndexedIter<'_, T> {
+        self.view().iter_indexed()
+    }
+}
+
+
+impl<T> std::ops::Index<&[usize]> for ArrayD<T> {
+    type Output = T;
+
+    /// Returns the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds or has a wrong number of dimensions:
+    /// the message contains the index and the shape.
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: &[usize]) -> &T {
+        self.strided.check_index(index);
+        &self.data[self.strided.position(index)]
+    }
+}
+
+
+impl<T, const N: usize> std::ops::Index<[usize; N]> for ArrayD<T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: [usize; N]) -> &T {
+        &self[&index[..]]
+    }
+}
+
+
+impl<T> std::ops::IndexMut<&[usize]> for ArrayD<T> {
+
+    /// Returns the element at `index` mutably.
+    ///
+    /// # Panics
+    ///
+    /// if the index is out of bounds or has a wrong number of dimensions:
+    /// the message contains the index and the shape.
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: &[usize]) -> &mut T {
+        self.strided.check_index(index);
+        let position = self.strided.position(index);
+        &mut self.data[position]
+    }
+}
+
+
+impl<T, const N: usize> std::ops::IndexMut<[usize; N]> for ArrayD<T> {
+
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+        &mut self[&index[..]]
+    }
+}
+
+
+impl<T: Clone> Clone for ArrayD<T> {
+
+    #[inline]
+    fn clone(&self) -> Self {
+        ArrayD { data: self.data.clone(), strided: self.strided.clone() }
+    }
+}
+
+
+impl<T, U> PartialEq<ArrayD<U>> for ArrayD<T>
+    where T: PartialEq<U>
+{
+    /// Compares the shapes and the elements.
+    #[inline]
+    fn eq(&self, other: &ArrayD<U>) -> bool {
+        self.shape() == other.shape() && *self.data == *other.data
+    }
+}
+
+
+impl<T: Eq> Eq for ArrayD<T> {}
+
+
+impl<T: fmt::Debug> fmt::Debug for ArrayD<T> {
+
+    /// Formats the array as nested lists, one level for each axis: `[[1, 2], [3, 4]]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Nested(self.view()).fmt(f)
+    }
+}
+
+
+impl<T> From<Array<T>> for ArrayD<T> {
+
+    /// Converts the flat array into a one-dimensional one without copying.
+    #[inline]
+    fn from(data: Array<T>) -> Self {
+        let shape = Array::from(&[data.size()][..]);
+        ArrayD::from_parts(data, shape)
+    }
+}
+
+
+impl<T> From<Array2<T>> for ArrayD<T> {
+
+    /// Converts the matrix into a two-dimensional array.
+    ///
+    /// Does not copy the row-major matrices, the column-major ones are
+    /// [rearranged](Array2::into_order) first.
+    fn from(matrix: Array2<T>) -> Self {
+        let (rows, cols) = matrix.shape();
+        let data = matrix.into_order(Order::RowMajor).into_array();
+        ArrayD::from_parts(data, Array::from(&[rows, cols][..]))
+    }
+}
+
+
+impl<T> From<ArrayD<T>> for Array<T> {
+
+    /// Converts the array into the flat array of its elements in the row-major order
+    /// without copying, like [`into_array`](ArrayD::into_array).
+    #[inline]
+    fn from(arr: ArrayD<T>) -> Self {
+        arr.into_array()
+    }
+}
@@ -0,0 +1,178 @@
+//! Provides the elementwise arithmetic operators with broadcasting
+//! between [`Array`], [`Array2`], [`ArrayD`] and [`ArrayViewD`]
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+
+use super::{Array, Array2, ArrayD, ArrayViewD, ArrayViewMutD};
+use super::arrayd_views::StridedD;
+
+
+/// Sees the elements of an array as a multi-dimensional view.
+trait AsViewD<T> {
+    fn as_view_d(&self) -> ArrayViewD<'_, T>;
+}
+
+
+impl<T> AsViewD<T> for Array<T> {
+
+    /// One axis of length `size`.
+    #[inline]
+    fn as_view_d(&self) -> ArrayViewD<'_, T> {
+        // can not fail: the shape is the array's own one
+        self.broadcast_to(&[self.size()]).unwrap()
+    }
+}
+
+
+impl<T> AsViewD<T> for Array2<T> {
+
+    /// The rows and the columns, whatever the order is.
+    #[inline]
+    fn as_view_d(&self) -> ArrayViewD<'_, T> {
+        self.broadcast_to(&[self.rows(), self.cols()]).unwrap()
+    }
+}
+
+
+impl<T> AsViewD<T> for ArrayD<T> {
+
+    #[inline]
+    fn as_view_d(&self) -> ArrayViewD<'_, T> {
+        self.view()
+    }
+}
+
+
+impl<T> AsViewD<T> for ArrayViewD<'_, T> {
+
+    #[inline]
+    fn as_view_d(&self) -> ArrayViewD<'_, T> {
+        self.clone()
+    }
+}
+
+
+/// Sees the elements of an array as a mutable multi-dimensional view.
+trait AsViewMutD<T> {
+    fn as_view_mut_d(&mut self) -> ArrayViewMutD<'_, T>;
+}
+
+
+impl<T> AsViewMutD<T> for Array<T> {
+
+    /// One axis of length `size`.
+    #[inline]
+    fn as_view_mut_d(&mut self) -> ArrayViewMutD<'_, T> {
+        // the view borrows the array mutably and sees all its elements once
+        unsafe { ArrayViewMutD::new(self.pointer, StridedD::contiguous(&[self.size()])) }
+    }
+}
+
+
+impl<T> AsViewMutD<T> for Array2<T> {
+
+    #[inline]
+    fn as_view_mut_d(&mut self) -> ArrayViewMutD<'_, T> {
+        // taking all the axes can not fail
+        self.slice_mut(&[]).unwrap()
+    }
+}
+
+
+impl<T> AsViewMutD<T> for ArrayD<T> {
+
+    #[inline]
+    fn as_view_mut_d(&mut self) -> ArrayViewMutD<'_, T> {
+        self.view_mut()
+    }
+}
+
+
+/// Implements `&lhs op &rhs` for the pairs of the array types,
+/// broadcasting the operands to the common shape.
+macro_rules! impl_binary_ops {
+    ($(($lhs:ty, $rhs:ty)),* $(,)?) => {$(
+        impl_binary_ops!(@op Add, add, $lhs, $rhs);
+        impl_binary_ops!(@op Sub, sub, $lhs, $rhs);
+        impl_binary_ops!(@op Mul, mul, $lhs, $rhs);
+        impl_binary_ops!(@op Div, div, $lhs, $rhs);
+        impl_binary_ops!(@op Rem, rem, $lhs, $rhs);
+    )*};
+    (@op $trait:ident, $method:ident, $lhs:ty, $rhs:ty) => {
+        impl<T, U> $trait<&$rhs> for &$lhs
+            where
+                T: Clone + $trait<U>,
+                U: Clone
+        {
+            type Output = ArrayD<<T as $trait<U>>::Output>;
+
+            /// Applies the operator to the elements, broadcast to the common shape,
+            /// and collects the results into a new array.
+            ///
+            /// # Panics
+            ///
+            /// if the shapes do not match for broadcasting.
+            #[track_caller]
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                let (lhs, rhs) = (self.as_view_d(), rhs.as_view_d());
+                match lhs.zip_with(&rhs, |a, b| $trait::$method(a.clone(), b.clone())) {
+                    Ok(result) => result,
+                    Err(err) => panic!("{err}"),
+                }
+            }
+        }
+    };
+}
+
+impl_binary_ops!(
+    (ArrayD<T>, ArrayD<U>), (ArrayD<T>, ArrayViewD<'_, U>), (ArrayD<T>, Array2<U>), (ArrayD<T>, Array<U>),
+    (ArrayViewD<'_, T>, ArrayD<U>), (ArrayViewD<'_, T>, ArrayViewD<'_, U>),
+    (ArrayViewD<'_, T>, Array2<U>), (ArrayViewD<'_, T>, Array<U>),
+    (Array2<T>, ArrayD<U>), (Array2<T>, ArrayViewD<'_, U>), (Array2<T>, Array2<U>), (Array2<T>, Array<U>),
+    (Array<T>, ArrayD<U>), (Array<T>, ArrayViewD<'_, U>), (Array<T>, Array2<U>), (Array<T>, Array<U>),
+);
+
+
+/// Implements `lhs op= &rhs` for the arrays,
+/// broadcasting the right operand to the shape of the left one.
+macro_rules! impl_assign_ops {
+    ($(($lhs:ty, $rhs:ty)),* $(,)?) => {$(
+        impl_assign_ops!(@op AddAssign, add_assign, $lhs, $rhs);
+        impl_assign_ops!(@op SubAssign, sub_assign, $lhs, $rhs);
+        impl_assign_ops!(@op MulAssign, mul_assign, $lhs, $rhs);
+        impl_assign_ops!(@op DivAssign, div_assign, $lhs, $rhs);
+        impl_assign_ops!(@op RemAssign, rem_assign, $lhs, $rhs);
+    )*};
+    (@op $trait:ident, $method:ident, $lhs:ty, $rhs:ty) => {
+        impl<T, U> $trait<&$rhs> for $lhs
+            where
+                T: $trait<U>,
+                U: Clone
+        {
+            /// Applies the operator to the elements and the ones of `rhs`,
+            /// broadcast to the shape of this array.
+            ///
+            /// # Panics
+            ///
+            /// if `rhs` can not be broadcast to the shape of this array.
+            #[track_caller]
+            fn $method(&mut self, rhs: &$rhs) {
+                let mut lhs = self.as_view_mut_d();
+                let rhs = match rhs.as_view_d().broadcast_to(lhs.shape()) {
+                    Ok(rhs) => rhs,
+                    Err(err) => panic!("{err}"),
+                };
+                for (a, b) in lhs.iter_mut().zip(rhs.iter()) {
+                    $trait::$method(a, b.clone());
+                }
+            }
+        }
+    };
+}
+
+impl_assign_ops!(
+    (ArrayD<T>, ArrayD<U>), (ArrayD<T>, ArrayViewD<'_, U>), (ArrayD<T>, Array2<U>), (ArrayD<T>, Array<U>),
+    (Array2<T>, ArrayD<U>), (Array2<T>, ArrayViewD<'_, U>), (Array2<T>, Array2<U>), (Array2<T>, Array<U>),
+    (Array<T>, ArrayD<U>), (Array<T>, ArrayViewD<'_, U>), (Array<T>, Array2<U>), (Array<T>, Array<U>),
+);
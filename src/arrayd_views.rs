@@ -0,0 +1,1239 @@
+//! Provides borrowed, strided views into an [`ArrayD`](crate::ArrayD) of any number of dimensions
+
+use crate::array::Array;
+use crate::array_views::Strided;
+use crate::arrayd::shape_size;
+use crate::error::ArrayError;
+use crate::slicing::AxisSlice;
+
+pub use view::ArrayViewD;
+pub use view_mut::ArrayViewMutD;
+pub use iter::{IndexedIter, ViewIterD, ViewIterMutD};
+pub(crate) use view::Nested;
+
+
+/// Positions of the elements of a multi-dimensional view in the underlying array:
+/// the element at the index `[i0, i1, ...]` is the
+/// `offset + i0 * strides[0] + i1 * strides[1] + ...`-th element of the array.
+///
+/// Every position of the indices in bounds of `shape` is in bounds of the array.
+/// The offset of an empty view means nothing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct StridedD {
+    pub(crate) offset: usize,
+    pub(crate) shape: Array<usize>,
+    pub(crate) strides: Array<isize>,
+}
+
+
+impl StridedD {
+
+    /// All the elements of an array of the given shape in the row-major order.
+    #[inline]
+    pub(crate) fn contiguous(shape: &[usize]) -> Self {
+        StridedD { offset: 0, shape: shape.into(), strides: Self::row_major_strides(shape) }
+    }
+
+    /// Returns the strides of the elements of the given shape, stored in the row-major order.
+    pub(crate) fn row_major_strides(shape: &[usize]) -> Array<isize> {
+        let mut strides = Array::from_elem(shape.len(), 0isize);
+        let mut stride = 1isize;
+        for axis in (0..shape.len()).rev() {
+            strides[axis] = stride;
+            // can overflow only for zero-sized types, whose positions mean nothing
+            stride = stride.wrapping_mul(shape[axis] as isize);
+        }
+        strides
+    }
+
+    /// Returns the number of dimensions.
+    #[inline]
+    pub(crate) fn ndim(&self) -> usize {
+        self.shape.size()
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub(crate) fn size(&self) -> usize {
+        // the other axes of an empty view may be arbitrarily long
+        if self.shape.contains(&0) { 0 } else { self.shape.iter().product() }
+    }
+
+    /// Returns `true`, if the `index` is in bounds of the shape.
+    #[inline]
+    pub(crate) fn contains(&self, index: &[usize]) -> bool {
+        index.len() == self.ndim() && index.iter().zip(self.shape.iter()).all(|(i, n)| i < n)
+    }
+
+    /// Returns the position of the element at `index`, which must be in bounds.
+    #[inline]
+    pub(crate) fn position(&self, index: &[usize]) -> usize {
+        index.iter().zip(self.strides.iter()).fold(self.offset, |position, (&i, &stride)| {
+            position.wrapping_add((i as isize).wrapping_mul(stride) as usize)
+        })
+    }
+
+    #[track_caller]
+    pub(crate) fn check_index(&self, index: &[usize]) {
+        assert!(
+            self.contains(index),
+            "index {index:?} is out of bounds of the shape {:?}", &*self.shape
+        );
+    }
+
+    /// Returns `true`, if the elements follow each other in memory in the row-major order.
+    pub(crate) fn is_standard(&self) -> bool {
+        if self.shape.contains(&0) {
+            return true;
+        }
+        let mut expected = 1isize;
+        for (&len, &stride) in self.shape.iter().zip(self.strides.iter()).rev() {
+            // the stride of an axis of length one is never used
+            if len != 1 && stride != expected {
+                return false;
+            }
+            expected = expected.wrapping_mul(len as isize);
+        }
+        true
+    }
+
+    #[track_caller]
+    pub(crate) fn permute_axes(self, axes: &[usize]) -> Self {
+        let ndim = self.ndim();
+        let mut seen = Array::from_elem(ndim, false);
+        let valid = axes.len() == ndim && axes.iter().all(|&axis| {
+            axis < ndim && !std::mem::replace(&mut seen[axis], true)
+        });
+        assert!(valid, "axes {axes:?} are not a permutation of 0..{ndim}");
+        StridedD {
+            offset: self.offset,
+            shape: axes.iter().map(|&axis| self.shape[axis]).collect(),
+            strides: axes.iter().map(|&axis| self.strides[axis]).collect(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn reverse_axes(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+        self
+    }
+
+    #[track_caller]
+    pub(crate) fn index_axis(self, axis: usize, index: usize) -> Self {
+        let ndim = self.ndim();
+        assert!(axis < ndim, "axis {axis} is out of bounds of {ndim} dimensions");
+        let len = self.shape[axis];
+        assert!(index < len, "index {index} is out of bounds of the axis {axis} of length {len}");
+        let skip = |k: usize| if k < axis { k } else { k + 1 };
+        StridedD {
+            offset: self.offset.wrapping_add((index as isize).wrapping_mul(self.strides[axis]) as usize),
+            shape: Array::from_fn(ndim - 1, |k| self.shape[skip(k)]),
+            strides: Array::from_fn(ndim - 1, |k| self.strides[skip(k)]),
+        }
+    }
+
+    /// Applies the `info` to the axes: see [`s!`](crate::s).
+    pub(crate) fn slice(&self, info: &[AxisSlice]) -> Result<StridedD, ArrayError> {
+        let ndim = self.ndim();
+        let taken = info.iter().filter(|elem| **elem != AxisSlice::NewAxis).count();
+        if taken > ndim {
+            return Err(ArrayError::TooManyAxes { ndim, found: taken });
+        }
+        let capacity = ndim - taken + info.len();
+        let (mut shape, mut strides) = (Vec::with_capacity(capacity), Vec::with_capacity(capacity));
+        let mut offset = self.offset;
+        let mut axis = 0;
+        for elem in info {
+            match *elem {
+                AxisSlice::Range { start, end, step } => {
+                    let len = self.shape[axis];
+                    let end = end.unwrap_or(len);
+                    if start > end || end > len {
+                        return Err(ArrayError::SliceOutOfBounds { axis, start, end, len });
+                    }
+                    if step == 0 {
+                        return Err(ArrayError::ZeroStep { axis });
+                    }
+                    let lane = Strided { offset: 0, len, stride: self.strides[axis] };
+                    let lane = lane.slice(start..end).step_by(step);
+                    offset = offset.wrapping_add(lane.offset);
+                    shape.push(lane.len);
+                    strides.push(lane.stride);
+                    axis += 1;
+                }
+                AxisSlice::Index(index) => {
+                    let len = self.shape[axis];
+                    if index >= len {
+                        return Err(ArrayError::AxisIndexOutOfBounds { axis, index, len });
+                    }
+                    let shift = (index as isize).wrapping_mul(self.strides[axis]);
+                    offset = offset.wrapping_add(shift as usize);
+                    axis += 1;
+                }
+                AxisSlice::NewAxis => {
+                    shape.push(1);
+                    strides.push(0);
+                }
+            }
+        }
+        shape.extend_from_slice(&self.shape[axis..]);
+        strides.extend_from_slice(&self.strides[axis..]);
+        Ok(StridedD { offset, shape: Array::from(shape), strides: Array::from(strides) })
+    }
+
+    /// Stretches the view to the `shape` by the broadcasting rules,
+    /// repeating the elements along the new and the stretched axes with the zero stride.
+    pub(crate) fn broadcast_to(&self, shape: &[usize]) -> Result<StridedD, ArrayError> {
+        shape_size(shape)?;
+        let ndim = self.ndim();
+        let mismatch = || ArrayError::ShapeMismatch { left: self.shape[..].into(), right: shape.into() };
+        // the new axes go first
+        let extra = shape.len().checked_sub(ndim).ok_or_else(mismatch)?;
+        let mut strides = Array::from_elem(shape.len(), 0isize);
+        for axis in 0..ndim {
+            let len = self.shape[axis];
+            if len == shape[extra + axis] {
+                strides[extra + axis] = self.strides[axis];
+            } else if len != 1 {
+                return Err(mismatch());
+            }
+        }
+        Ok(StridedD { offset: self.offset, shape: shape.into(), strides })
+    }
+}
+
+
+/// Returns the shape, to which both shapes are broadcast:
+/// the axes are aligned at the end, the missing ones and the ones of length one are stretched.
+pub(crate) fn broadcast_shape(left: &[usize], right: &[usize]) -> Result<Array<usize>, ArrayError> {
+    let ndim = left.len().max(right.len());
+    // the length of the axis, counted from the end, or one if it is missing
+    let len = |shape: &[usize], k: usize| if k < shape.len() { shape[shape.len() - 1 - k] } else { 1 };
+    let mut shape = Array::from_elem(ndim, 0);
+    for k in 0..ndim {
+        shape[ndim - 1 - k] = match (len(left, k), len(right, k)) {
+            (a, b) if a == b || b == 1 => a,
+            (1, b) => b,
+            _ => return Err(ArrayError::ShapeMismatch { left: left.into(), right: right.into() }),
+        };
+    }
+    shape_size(&shape)?;
+    Ok(shape)
+}
+
+
+/// Walks over the indices of a shape in the row-major order,
+/// keeping the position of the current one.
+#[derive(Clone)]
+pub(crate) struct Cursor {
+    strided: StridedD,
+    // the current index and its position
+    index: Array<usize>,
+    position: usize,
+    // the number of the indices left, including the current one
+    len: usize,
+}
+
+
+impl Cursor {
+
+    pub(crate) fn new(strided: StridedD) -> Self {
+        let index = Array::from_elem(strided.ndim(), 0);
+        let (position, len) = (strided.offset, strided.size());
+        Cursor { strided, index, position, len }
+    }
+
+    /// Returns the current index.
+    #[inline]
+    pub(crate) fn index(&self) -> &[usize] {
+        &self.index
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the current position and moves to the next index, if any are left.
+    pub(crate) fn next_position(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        let position = self.position;
+        self.len -= 1;
+        if self.len != 0 {
+            for axis in (0..self.index.size()).rev() {
+                let stride = self.strided.strides[axis];
+                self.index[axis] += 1;
+                self.position = self.position.wrapping_add(stride as usize);
+                if self.index[axis] < self.strided.shape[axis] {
+                    break;
+                }
+                // back to the start of the axis, carrying to the previous one
+                let len = self.strided.shape[axis] as isize;
+                self.position = self.position.wrapping_sub(len.wrapping_mul(stride) as usize);
+                self.index[axis] = 0;
+            }
+        }
+        Some(position)
+    }
+}
+
+
+mod view {
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use crate::array::Array;
+    use crate::arrayd::ArrayD;
+    use crate::error::ArrayError;
+    use crate::slicing::AxisSlice;
+    use super::{broadcast_shape, IndexedIter, StridedD, ViewIterD};
+
+    /// Immutable, strided view of the elements of an [`ArrayD`] in any number of dimensions.
+    ///
+    /// The element at the index `[i0, i1, ...]` is the
+    /// [`offset`](ArrayViewD::offset)` + i0 * strides[0] + i1 * strides[1] + ...`-th
+    /// element of the array, so the axes of the view may be reordered or fixed without copying.
+    ///
+    /// Borrows the array like `&'a [T]`: any number of views may exist at the same time.
+    ///
+    /// This `struct` is created by the [`view`](ArrayD::view),
+    /// [`permute_axes`](ArrayD::permute_axes), [`transpose`](ArrayD::transpose)
+    /// and [`index_axis`](ArrayD::index_axis) methods on `ArrayD`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let grid = ArrayD::from_fn(&[2, 3, 4], |index| index.to_vec());
+    /// let view = grid.permute_axes(&[2, 0, 1]);
+    /// assert_eq!(view.shape(), &[4, 2, 3]);
+    /// assert_eq!(view[[3, 1, 2]], [1, 2, 3]);
+    /// ```
+    pub struct ArrayViewD<'a, T> {
+        // the view must not outlive the borrow of the array
+        marker: PhantomData<&'a T>,
+        base: NonNull<T>,
+        strided: StridedD,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for ArrayViewD<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ArrayViewD<'_, T> {}
+
+
+    impl<'a, T> ArrayViewD<'a, T> {
+
+        /// # Safety
+        ///
+        /// The elements of `strided` must be positions of initialized elements at `base`,
+        /// which are borrowed immutably for `'a`.
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: StridedD) -> Self {
+            Self { marker: PhantomData, base, strided }
+        }
+
+        /// Returns the lengths of the axes.
+        #[inline]
+        pub fn shape(&self) -> &[usize] {
+            &self.strided.shape
+        }
+
+        /// Returns the distances between the neighbouring elements along each axis
+        /// in the array, measured in elements.
+        #[inline]
+        pub fn strides(&self) -> &[isize] {
+            &self.strided.strides
+        }
+
+        /// Returns the index of the first element of the view in the array.
+        ///
+        /// Means nothing for empty views.
+        #[inline]
+        pub fn offset(&self) -> usize {
+            self.strided.offset
+        }
+
+        /// Returns the number of dimensions.
+        #[inline]
+        pub fn ndim(&self) -> usize {
+            self.strided.ndim()
+        }
+
+        /// Returns the number of elements in the view.
+        #[inline]
+        pub fn size(&self) -> usize {
+            self.strided.size()
+        }
+
+        /// Returns `true`, if the view has no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.size() == 0
+        }
+
+        /// Returns reference at an element
+        /// or None if the given index is out of bounds or has a wrong number of dimensions.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+        /// let view = arr.transpose();
+        /// assert_eq!(view.try_get(&[2, 1]), Some(&5));
+        /// assert_eq!(view.try_get(&[1, 2]), None);
+        /// assert_eq!(view.try_get(&[1]), None);
+        /// ```
+        #[inline]
+        pub fn try_get(&self, index: &[usize]) -> Option<&'a T> {
+            if self.strided.contains(index) {
+                unsafe { Some(&*self.base.as_ptr().add(self.strided.position(index))) }
+            } else {
+                None
+            }
+        }
+
+        /// Returns an iterator over the elements of the view in the row-major order.
+        #[inline]
+        pub fn iter(&self) -> ViewIterD<'a, T> {
+            unsafe { ViewIterD::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns an iterator over the indices and the elements of the view in the row-major order.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let arr = ArrayD::from_fn(&[2, 2], |index| index[0] * 2 + index[1]);
+        /// let mut iter = arr.transpose().iter_indexed();
+        /// assert_eq!(iter.next(), Some(([0, 0].into(), &0)));
+        /// assert_eq!(iter.next(), Some(([0, 1].into(), &2)));
+        /// ```
+        #[inline]
+        pub fn iter_indexed(&self) -> IndexedIter<'a, T> {
+            unsafe { IndexedIter::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns the view with the axes reordered:
+        /// the `k`-th axis of the result is the `axes[k]`-th axis of this view.
+        ///
+        /// # Panics
+        ///
+        /// if `axes` is not a permutation of `0..ndim`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let arr: ArrayD<u8> = ArrayD::new(&[2, 3, 4]).unwrap();
+        /// assert_eq!(arr.view().permute_axes(&[1, 2, 0]).shape(), &[3, 4, 2]);
+        /// ```
+        #[inline]
+        #[track_caller]
+        pub fn permute_axes(self, axes: &[usize]) -> Self {
+            unsafe { Self::new(self.base, self.strided.permute_axes(axes)) }
+        }
+
+        /// Returns the view with the axes in the reverse order.
+        #[inline]
+        pub fn transpose(self) -> Self {
+            unsafe { Self::new(self.base, self.strided.reverse_axes()) }
+        }
+
+        /// Returns the view of the elements with the given `index` along the `axis`,
+        /// which has one dimension less.
+        ///
+        /// # Panics
+        ///
+        /// if the `axis` or the `index` is out of bounds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+        /// let col = arr.view().index_axis(1, 2);
+        /// assert_eq!(col.shape(), &[2]);
+        /// assert_eq!(col.iter().copied().collect::<Vec<_>>(), [2, 5]);
+        /// ```
+        #[inline]
+        #[track_caller]
+        pub fn index_axis(self, axis: usize, index: usize) -> Self {
+            unsafe { Self::new(self.base, self.strided.index_axis(axis, index)) }
+        }
+
+        /// Returns the view of the elements, selected by `info` from this view: see [`s!`](crate::s).
+        ///
+        /// Returns `ArrayError`, if `info` takes more axes than the view has,
+        /// a range or an index is out of bounds of its axis or a step is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::{s, ArrayD};
+        ///
+        /// let arr = ArrayD::from_fn(&[3, 4], |index| index[0] * 4 + index[1]);
+        /// let corner = arr.view().slice(s![1.., ..2]).unwrap();
+        /// assert_eq!(corner.to_arrayd().as_slice(), &[4, 5, 8, 9]);
+        /// ```
+        #[inline]
+        pub fn slice(&self, info: &[AxisSlice]) -> Result<Self, ArrayError> {
+            unsafe { Ok(Self::new(self.base, self.strided.slice(info)?)) }
+        }
+
+        /// Returns the view of the elements stretched to the `shape` by the broadcasting rules,
+        /// like in NumPy, without copying.
+        ///
+        /// The axes are aligned at the end. The missing leading axes are added,
+        /// the axes of length one are stretched: the elements along them are repeated
+        /// with the zero [stride](ArrayViewD::strides).
+        ///
+        /// Returns `ArrayError`, if any of the following cases happened:
+        /// * an axis can not be stretched to the `shape`
+        ///   ([`ShapeMismatch`](ArrayError::ShapeMismatch)),
+        /// * the number of elements of the `shape` overflows
+        ///   ([`ShapeOverflow`](ArrayError::ShapeOverflow)).
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let bias = ArrayD::from_fn(&[3], |index| index[0]);
+        /// let stretched = bias.view().broadcast_to(&[2, 3]).unwrap();
+        /// assert_eq!(stretched.strides(), &[0, 1]);
+        /// assert_eq!(stretched.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 0, 1, 2]);
+        ///
+        /// assert!(bias.view().broadcast_to(&[3, 2]).is_err());
+        /// ```
+        #[inline]
+        pub fn broadcast_to(&self, shape: &[usize]) -> Result<Self, ArrayError> {
+            // the repeated elements are shared, which is fine for the immutable views
+            unsafe { Ok(Self::new(self.base, self.strided.broadcast_to(shape)?)) }
+        }
+
+        /// Applies `f` to the pairs of the elements of the views, broadcast to the common shape,
+        /// and collects the results into a new array of this shape.
+        ///
+        /// Returns `ArrayError`, if the shapes do not match for broadcasting
+        /// ([`ShapeMismatch`](ArrayError::ShapeMismatch)) or the common shape overflows
+        /// ([`ShapeOverflow`](ArrayError::ShapeOverflow)).
+        ///
+        /// # Panics
+        ///
+        /// if failed allocating memory for the array or `f` panicked.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let col = ArrayD::from_fn(&[2, 1], |index| index[0] * 10);
+        /// let row = ArrayD::from_fn(&[3], |index| index[0]);
+        /// let table = col.view().zip_with(&row.view(), |a, b| a + b).unwrap();
+        /// assert_eq!(table.shape(), &[2, 3]);
+        /// assert_eq!(table.as_slice(), &[0, 1, 2, 10, 11, 12]);
+        /// ```
+        pub fn zip_with<U, O, F>(&self, other: &ArrayViewD<'_, U>, mut f: F) -> Result<ArrayD<O>, ArrayError>
+            where F: FnMut(&T, &U) -> O
+        {
+            let shape = broadcast_shape(self.shape(), other.shape())?;
+            let (left, right) = (self.broadcast_to(&shape)?, other.broadcast_to(&shape)?);
+            let data = Array::from_exact_iter(left.iter().zip(right.iter()).map(|(a, b)| f(a, b)));
+            Ok(ArrayD::from_parts(data, shape))
+        }
+
+        /// Returns the elements of the view as a slice,
+        /// if they follow each other in memory in the row-major order.
+        #[inline]
+        pub fn as_slice(&self) -> Option<&'a [T]> {
+            if !self.strided.is_standard() {
+                return None;
+            }
+            unsafe {
+                let first = self.base.as_ptr().wrapping_add(self.strided.offset);
+                Some(std::slice::from_raw_parts(first, self.size()))
+            }
+        }
+
+        /// Copies the elements of the view into a new array of the same shape.
+        ///
+        /// # Panics
+        ///
+        /// if failed allocating memory for the array or `T::clone` panicked.
+        pub fn to_arrayd(&self) -> ArrayD<T>
+            where T: Clone
+        {
+            let data = Array::from_exact_iter(self.iter().cloned());
+            ArrayD::from_parts(data, self.strided.shape.clone())
+        }
+    }
+
+
+    impl<T> Clone for ArrayViewD<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            unsafe { Self::new(self.base, self.strided.clone()) }
+        }
+    }
+
+
+    impl<T> std::ops::Index<&[usize]> for ArrayViewD<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: &[usize]) -> &T {
+            self.strided.check_index(index);
+            unsafe { &*self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<T, const N: usize> std::ops::Index<[usize; N]> for ArrayViewD<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: [usize; N]) -> &T {
+            &self[&index[..]]
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for ArrayViewD<'a, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIterD<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &ArrayViewD<'a, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIterD<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<T, U> PartialEq<ArrayViewD<'_, U>> for ArrayViewD<'_, T>
+        where T: PartialEq<U>
+    {
+        /// Compares the shapes and the elements at the same indices.
+        #[inline]
+        fn eq(&self, other: &ArrayViewD<'_, U>) -> bool {
+            self.shape() == other.shape() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ArrayViewD<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ArrayViewD").field(&Nested(self.clone())).finish()
+        }
+    }
+
+
+    /// Formats the elements of a view as nested lists, one level for each axis.
+    pub(crate) struct Nested<'a, T>(pub(crate) ArrayViewD<'a, T>);
+
+    impl<T: fmt::Debug> fmt::Debug for Nested<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let view = &self.0;
+            if view.ndim() == 0 {
+                return view[[]].fmt(f);
+            }
+            let sub = |i| Nested(view.clone().index_axis(0, i));
+            f.debug_list().entries((0..view.shape()[0]).map(sub)).finish()
+        }
+    }
+}
+
+
+mod view_mut {
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use crate::array::Array;
+    use crate::arrayd::ArrayD;
+    use crate::error::ArrayError;
+    use crate::slicing::AxisSlice;
+    use super::{ArrayViewD, IndexedIter, Nested, StridedD, ViewIterD, ViewIterMutD};
+
+    /// Mutable, strided view of the elements of an [`ArrayD`] in any number of dimensions.
+    ///
+    /// Borrows the array like `&'a mut [T]`: while the view exists,
+    /// nothing else can access the elements it sees.
+    ///
+    /// This `struct` is created by the [`view_mut`](ArrayD::view_mut)
+    /// and [`index_axis_mut`](ArrayD::index_axis_mut) methods on `ArrayD`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let mut grid: ArrayD<i32> = ArrayD::new(&[2, 2, 2]).unwrap();
+    /// let mut layer = grid.index_axis_mut(2, 1);
+    /// layer[[1, 0]] = 5;
+    /// assert_eq!(grid[[1, 0, 1]], 5);
+    /// ```
+    pub struct ArrayViewMutD<'a, T> {
+        // the view must not outlive the mutable borrow of the array
+        marker: PhantomData<&'a mut T>,
+        base: NonNull<T>,
+        strided: StridedD,
+    }
+
+
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for ArrayViewMutD<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ArrayViewMutD<'_, T> {}
+
+
+    impl<'a, T> ArrayViewMutD<'a, T> {
+
+        /// # Safety
+        ///
+        /// The elements of `strided` must be distinct positions of initialized elements at `base`,
+        /// which are borrowed mutably for `'a` and not accessed by anything else during it.
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: StridedD) -> Self {
+            Self { marker: PhantomData, base, strided }
+        }
+
+        /// Returns the lengths of the axes.
+        #[inline]
+        pub fn shape(&self) -> &[usize] {
+            &self.strided.shape
+        }
+
+        /// Returns the distances between the neighbouring elements along each axis
+        /// in the array, measured in elements.
+        #[inline]
+        pub fn strides(&self) -> &[isize] {
+            &self.strided.strides
+        }
+
+        /// Returns the index of the first element of the view in the array.
+        ///
+        /// Means nothing for empty views.
+        #[inline]
+        pub fn offset(&self) -> usize {
+            self.strided.offset
+        }
+
+        /// Returns the number of dimensions.
+        #[inline]
+        pub fn ndim(&self) -> usize {
+            self.strided.ndim()
+        }
+
+        /// Returns the number of elements in the view.
+        #[inline]
+        pub fn size(&self) -> usize {
+            self.strided.size()
+        }
+
+        /// Returns `true`, if the view has no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.size() == 0
+        }
+
+        /// Returns immutable reference at an element
+        /// or None if the given index is out of bounds or has a wrong number of dimensions.
+        #[inline]
+        pub fn try_get(&self, index: &[usize]) -> Option<&T> {
+            self.view().try_get(index)
+        }
+
+        /// Returns mutable reference at an element
+        /// or None if the given index is out of bounds or has a wrong number of dimensions.
+        #[inline]
+        pub fn try_get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+            if self.strided.contains(index) {
+                unsafe { Some(&mut *self.base.as_ptr().add(self.strided.position(index))) }
+            } else {
+                None
+            }
+        }
+
+        /// Returns an iterator over the elements of the view in the row-major order.
+        #[inline]
+        pub fn iter(&self) -> ViewIterD<'_, T> {
+            unsafe { ViewIterD::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns an iterator over the elements of the view in the row-major order,
+        /// that allows modifying each value.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use runtime_sized_array::ArrayD;
+        ///
+        /// let mut arr: ArrayD<i32> = ArrayD::new(&[2, 3]).unwrap();
+        /// let mut view = arr.view_mut().transpose();
+        /// for (k, x) in view.iter_mut().enumerate() {
+        ///     *x = k as i32;
+        /// }
+        /// assert_eq!(arr.as_slice(), &[0, 2, 4, 1, 3, 5]);
+        /// ```
+        #[inline]
+        pub fn iter_mut(&mut self) -> ViewIterMutD<'_, T> {
+            unsafe { ViewIterMutD::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns an iterator over the indices and the elements of the view in the row-major order.
+        #[inline]
+        pub fn iter_indexed(&self) -> IndexedIter<'_, T> {
+            unsafe { IndexedIter::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns an immutable view of the same elements.
+        #[inline]
+        pub fn view(&self) -> ArrayViewD<'_, T> {
+            unsafe { ArrayViewD::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns a mutable view of the same elements, which borrows this one.
+        ///
+        /// Allows calling the consuming methods without giving up this view.
+        #[inline]
+        pub fn reborrow(&mut self) -> ArrayViewMutD<'_, T> {
+            unsafe { ArrayViewMutD::new(self.base, self.strided.clone()) }
+        }
+
+        /// Returns the view with the axes reordered:
+        /// the `k`-th axis of the result is the `axes[k]`-th axis of this view.
+        ///
+        /// # Panics
+        ///
+        /// if `axes` is not a permutation of `0..ndim`.
+        #[inline]
+        #[track_caller]
+        pub fn permute_axes(self, axes: &[usize]) -> Self {
+            unsafe { Self::new(self.base, self.strided.permute_axes(axes)) }
+        }
+
+        /// Returns the view with the axes in the reverse order.
+        #[inline]
+        pub fn transpose(self) -> Self {
+            unsafe { Self::new(self.base, self.strided.reverse_axes()) }
+        }
+
+        /// Returns the view of the elements with the given `index` along the `axis`,
+        /// which has one dimension less.
+        ///
+        /// # Panics
+        ///
+        /// if the `axis` or the `index` is out of bounds.
+        #[inline]
+        #[track_caller]
+        pub fn index_axis(self, axis: usize, index: usize) -> Self {
+            unsafe { Self::new(self.base, self.strided.index_axis(axis, index)) }
+        }
+
+        /// Returns the view of the elements, selected by `info` from this view: see [`s!`](crate::s).
+        ///
+        /// Returns `ArrayError`, if `info` takes more axes than the view has,
+        /// a range or an index is out of bounds of its axis or a step is zero.
+        #[inline]
+        pub fn slice(self, info: &[AxisSlice]) -> Result<Self, ArrayError> {
+            // the slices of an axis of the view never select an element twice
+            unsafe { Ok(Self::new(self.base, self.strided.slice(info)?)) }
+        }
+
+        /// Returns the elements of the view as a slice,
+        /// if they follow each other in memory in the row-major order.
+        #[inline]
+        pub fn as_slice(&self) -> Option<&[T]> {
+            self.view().as_slice()
+        }
+
+        /// Returns the elements of the view as a mutable slice,
+        /// if they follow each other in memory in the row-major order.
+        #[inline]
+        pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+            if !self.strided.is_standard() {
+                return None;
+            }
+            unsafe {
+                let first = self.base.as_ptr().wrapping_add(self.strided.offset);
+                Some(std::slice::from_raw_parts_mut(first, self.size()))
+            }
+        }
+
+        /// Copies the elements of the view into a new array of the same shape.
+        ///
+        /// # Panics
+        ///
+        /// if failed allocating memory for the array or `T::clone` panicked.
+        pub fn to_arrayd(&self) -> ArrayD<T>
+            where T: Clone
+        {
+            let data = Array::from_exact_iter(self.iter().cloned());
+            ArrayD::from_parts(data, self.strided.shape.clone())
+        }
+    }
+
+
+    impl<T> std::ops::Index<&[usize]> for ArrayViewMutD<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: &[usize]) -> &T {
+            self.strided.check_index(index);
+            unsafe { &*self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<T, const N: usize> std::ops::Index<[usize; N]> for ArrayViewMutD<'_, T> {
+        type Output = T;
+
+        #[inline]
+        #[track_caller]
+        fn index(&self, index: [usize; N]) -> &T {
+            &self[&index[..]]
+        }
+    }
+
+
+    impl<T> std::ops::IndexMut<&[usize]> for ArrayViewMutD<'_, T> {
+
+        #[inline]
+        #[track_caller]
+        fn index_mut(&mut self, index: &[usize]) -> &mut T {
+            self.strided.check_index(index);
+            unsafe { &mut *self.base.as_ptr().add(self.strided.position(index)) }
+        }
+    }
+
+
+    impl<T, const N: usize> std::ops::IndexMut<[usize; N]> for ArrayViewMutD<'_, T> {
+
+        #[inline]
+        #[track_caller]
+        fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+            &mut self[&index[..]]
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for ArrayViewMutD<'a, T> {
+        type Item = &'a mut T;
+        type IntoIter = ViewIterMutD<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            unsafe { ViewIterMutD::new(self.base, self.strided) }
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &'a ArrayViewMutD<'_, T> {
+        type Item = &'a T;
+        type IntoIter = ViewIterD<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+
+    impl<'a, T> IntoIterator for &'a mut ArrayViewMutD<'_, T> {
+        type Item = &'a mut T;
+        type IntoIter = ViewIterMutD<'a, T>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter_mut()
+        }
+    }
+
+
+    impl<T, U> PartialEq<ArrayViewMutD<'_, U>> for ArrayViewMutD<'_, T>
+        where T: PartialEq<U>
+    {
+        /// Compares the shapes and the elements at the same indices.
+        #[inline]
+        fn eq(&self, other: &ArrayViewMutD<'_, U>) -> bool {
+            self.view() == other.view()
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ArrayViewMutD<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ArrayViewMutD").field(&Nested(self.view())).finish()
+        }
+    }
+}
+
+
+mod iter {
+
+    use std::fmt;
+    use std::iter::FusedIterator;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+    use crate::array::Array;
+    use super::{Cursor, StridedD};
+    use crate::array_views::Entries;
+
+    /// Immutable iterator over the elements of a multi-dimensional view in the row-major order.
+    ///
+    /// This `struct` is created by the `iter` methods
+    /// on [`ArrayViewD`](super::ArrayViewD) and [`ArrayViewMutD`](super::ArrayViewMutD).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 3], |index| index[0] * 3 + index[1]);
+    /// let transposed: Vec<usize> = arr.transpose().iter().copied().collect();
+    /// assert_eq!(transposed, [0, 3, 1, 4, 2, 5]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct ViewIterD<'a, T> {
+        marker: PhantomData<&'a T>,
+        base: NonNull<T>,
+        cursor: Cursor,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for ViewIterD<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ViewIterD<'_, T> {}
+
+
+    impl<'a, T> ViewIterD<'a, T> {
+
+        /// # Safety
+        ///
+        /// The same as for [`ArrayViewD::new`](super::ArrayViewD::new).
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: StridedD) -> Self {
+            Self { marker: PhantomData, base, cursor: Cursor::new(strided) }
+        }
+    }
+
+
+    impl<'a, T> Iterator for ViewIterD<'a, T> {
+        type Item = &'a T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let position = self.cursor.next_position()?;
+            unsafe { Some(&*self.base.as_ptr().add(position)) }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.cursor.len(), Some(self.cursor.len()))
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for ViewIterD<'_, T> {}
+
+
+    impl<T> FusedIterator for ViewIterD<'_, T> {}
+
+
+    impl<T> Clone for ViewIterD<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            Self { marker: PhantomData, base: self.base, cursor: self.cursor.clone() }
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for ViewIterD<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("ViewIterD").field(&Entries(self.clone())).finish()
+        }
+    }
+
+
+    /// Mutable iterator over the elements of a multi-dimensional view in the row-major order.
+    ///
+    /// This `struct` is created by the [`iter_mut`](super::ArrayViewMutD::iter_mut) method
+    /// on [`ArrayViewMutD`](super::ArrayViewMutD).
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct ViewIterMutD<'a, T> {
+        marker: PhantomData<&'a mut T>,
+        base: NonNull<T>,
+        cursor: Cursor,
+    }
+
+
+    // behaves like `&'a mut [T]`
+    unsafe impl<T: Send> Send for ViewIterMutD<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for ViewIterMutD<'_, T> {}
+
+
+    impl<'a, T> ViewIterMutD<'a, T> {
+
+        /// # Safety
+        ///
+        /// The same as for [`ArrayViewMutD::new`](super::ArrayViewMutD::new).
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: StridedD) -> Self {
+            Self { marker: PhantomData, base, cursor: Cursor::new(strided) }
+        }
+    }
+
+
+    impl<'a, T> Iterator for ViewIterMutD<'a, T> {
+        type Item = &'a mut T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let position = self.cursor.next_position()?;
+            // every position is yielded once and they are distinct
+            unsafe { Some(&mut *self.base.as_ptr().add(position)) }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.cursor.len(), Some(self.cursor.len()))
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for ViewIterMutD<'_, T> {}
+
+
+    impl<T> FusedIterator for ViewIterMutD<'_, T> {}
+
+
+    impl<T: fmt::Debug> fmt::Debug for ViewIterMutD<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let remaining = ViewIterD { marker: PhantomData, base: self.base, cursor: self.cursor.clone() };
+            f.debug_tuple("ViewIterMutD").field(&Entries(remaining)).finish()
+        }
+    }
+
+
+    /// Iterator over the indices and the elements of a multi-dimensional view
+    /// in the row-major order.
+    ///
+    /// Every index is a new array of `ndim` elements.
+    ///
+    /// This `struct` is created by the `iter_indexed` methods on [`ArrayD`](crate::ArrayD),
+    /// [`ArrayViewD`](super::ArrayViewD) and [`ArrayViewMutD`](super::ArrayViewMutD).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::ArrayD;
+    ///
+    /// let arr = ArrayD::from_fn(&[2, 2], |index| index[0] * 2 + index[1]);
+    /// for (index, x) in arr.iter_indexed() {
+    ///     assert_eq!(arr[&index[..]], *x);
+    /// }
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub struct IndexedIter<'a, T> {
+        marker: PhantomData<&'a T>,
+        base: NonNull<T>,
+        cursor: Cursor,
+    }
+
+
+    // behaves like `&'a [T]`
+    unsafe impl<T: Sync> Send for IndexedIter<'_, T> {}
+
+    unsafe impl<T: Sync> Sync for IndexedIter<'_, T> {}
+
+
+    impl<'a, T> IndexedIter<'a, T> {
+
+        /// # Safety
+        ///
+        /// The same as for [`ArrayViewD::new`](super::ArrayViewD::new).
+        #[inline]
+        pub(crate) unsafe fn new(base: NonNull<T>, strided: StridedD) -> Self {
+            Self { marker: PhantomData, base, cursor: Cursor::new(strided) }
+        }
+    }
+
+
+    impl<'a, T> Iterator for IndexedIter<'a, T> {
+        type Item = (Array<usize>, &'a T);
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.cursor.len() == 0 {
+                return None;
+            }
+            let index = Array::from(self.cursor.index());
+            let position = self.cursor.next_position()?;
+            unsafe { Some((index, &*self.base.as_ptr().add(position))) }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.cursor.len(), Some(self.cursor.len()))
+        }
+    }
+
+
+    impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
+
+
+    impl<T> FusedIterator for IndexedIter<'_, T> {}
+
+
+    impl<T> Clone for IndexedIter<'_, T> {
+
+        #[inline]
+        fn clone(&self) -> Self {
+            Self { marker: PhantomData, base: self.base, cursor: self.cursor.clone() }
+        }
+    }
+
+
+    impl<T: fmt::Debug> fmt::Debug for IndexedIter<'_, T> {
+
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("IndexedIter").field(&Entries(self.clone())).finish()
+        }
+    }
+}
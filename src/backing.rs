@@ -0,0 +1,67 @@
+//! Describes, who owns the memory of an [`Array`](crate::Array) and how it must be freed
+
+use std::alloc::Layout;
+
+
+/// The origin of the memory, the array points to.
+///
+/// The array always owns its elements and drops them,
+/// the backing only decides what happens to the memory after that.
+pub(crate) enum Backing {
+    /// Allocated by the global allocator with `Layout::array::<T>(size)`
+    /// (by the crate itself or by a `Box<[T]>`), or dangling, if the layout is zero-sized.
+    Owned,
+    /// Taken from a `Vec<T>` with the given capacity.
+    Vec { capacity: usize },
+    /// Owned by somebody else and never freed by the array.
+    Borrowed,
+    /// Freed by the user-supplied deallocator, which gets the pointer and the size.
+    // the pointer is erased to keep the array covariant in `T`
+    Foreign(Box<dyn FnOnce(*mut u8, usize) + Send + Sync>),
+}
+
+
+impl Backing {
+
+    /// Frees the memory of `size` elements of type `T` at `ptr`
+    /// in the way its origin requires.
+    ///
+    /// # Safety
+    ///
+    /// The elements must be already dropped or moved out,
+    /// `ptr` and `size` must be the ones the backing was created for.
+    pub(crate) unsafe fn release<T>(self, ptr: *mut T, size: usize) {
+        match self {
+            Backing::Owned => {
+                // the layout was successfully created, when the memory was allocated
+                let layout = Layout::array::<T>(size).unwrap_unchecked();
+                // nothing was allocated for empty arrays and zero-sized types
+                if layout.size() != 0 {
+                    std::alloc::dealloc(ptr as *mut u8, layout);
+                }
+            }
+            Backing::Vec { capacity } => drop(Vec::from_raw_parts(ptr, 0, capacity)),
+            Backing::Borrowed => {}
+            Backing::Foreign(dealloc) => dealloc(ptr as *mut u8, size),
+        }
+    }
+}
+
+
+/// Releases the memory by the backing, when dropped:
+/// also while unwinding from a panicking destructor of an element.
+pub(crate) struct ReleaseGuard<T> {
+    pub(crate) backing: Backing,
+    pub(crate) ptr: *mut T,
+    pub(crate) size: usize,
+}
+
+
+impl<T> Drop for ReleaseGuard<T> {
+
+    fn drop(&mut self) {
+        let backing = std::mem::replace(&mut self.backing, Backing::Borrowed);
+        // the guard is created for the backing of the elements, which are dropped before it
+        unsafe { backing.release(self.ptr, self.size) }
+    }
+}
@@ -1,13 +1,139 @@
-use std::alloc::LayoutError;
+use std::alloc::{Layout, LayoutError};
+use std::error::Error;
+use std::fmt;
+
+/// The error type of [`Array`](crate::Array) operations.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::{Array, ArrayError};
+///
+/// match Array::<u64>::new(usize::MAX) {
+///     Err(ArrayError::LayoutOverflow { elem_size, len, .. }) => {
+///         assert_eq!((elem_size, len), (8, usize::MAX))
+///     }
+///     _ => unreachable!()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArrayError {
+    /// The memory for `len` elements of size `elem_size` exceeds `isize::MAX` bytes.
+    LayoutOverflow { elem_size: usize, len: usize, source: LayoutError },
+    /// Failed creating a [`Layout`], converted from the error of its constructor
+    /// by the `From<LayoutError>` implementation.
+    ///
+    /// Only made by the conversion of the callers' own layout errors:
+    /// the array operations report their layout failures as
+    /// [`LayoutOverflow`](ArrayError::LayoutOverflow) with the size and the length.
+    Layout(LayoutError),
+    /// The allocator failed to allocate memory with the given layout.
+    AllocFailed { layout: Layout },
+    /// The length of the array differs from the expected one.
+    LengthMismatch { expected: usize, found: usize },
+    /// The index is out of the bounds of the array of length `len`.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The iterator yielded `got` items instead of `wanted` ones.
+    IteratorExhausted { wanted: usize, got: usize },
+    /// The same index was given more than once, where distinct ones are required.
+    DuplicateIndex { index: usize },
+    /// The number of elements of the shape exceeds `usize::MAX`.
+    ShapeOverflow,
+    /// The slice `start..end` does not fit the `axis` of length `len`.
+    SliceOutOfBounds { axis: usize, start: usize, end: usize, len: usize },
+    /// The index is out of the bounds of the `axis` of length `len`.
+    AxisIndexOutOfBounds { axis: usize, index: usize, len: usize },
+    /// The slice takes `found` axes of an array with only `ndim` ones.
+    TooManyAxes { ndim: usize, found: usize },
+    /// The step of the slice of the `axis` is zero.
+    ZeroStep { axis: usize },
+    /// The shapes can not be broadcast together.
+    ShapeMismatch { left: Box<[usize]>, right: Box<[usize]> },
+}
+
+
+impl ArrayError {
+
+    /// Creates [`LayoutOverflow`](ArrayError::LayoutOverflow) for `len` elements of type `T`.
+    #[inline]
+    pub(crate) fn layout_overflow<T>(len: usize, source: LayoutError) -> Self {
+        ArrayError::LayoutOverflow { elem_size: std::mem::size_of::<T>(), len, source }
+    }
+
+    /// Creates [`AllocFailed`](ArrayError::AllocFailed) for `len` elements of type `T`
+    /// or [`LayoutOverflow`](ArrayError::LayoutOverflow), if their layout overflows.
+    pub(crate) fn alloc_failed<T>(len: usize) -> Self {
+        match Layout::array::<T>(len) {
+            Ok(layout) => ArrayError::AllocFailed { layout },
+            Err(source) => ArrayError::layout_overflow::<T>(len, source),
+        }
+    }
+}
+
+
+impl fmt::Display for ArrayError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayError::LayoutOverflow { elem_size, len, .. } => write!(
+                f, "layout of {len} elements of size {elem_size} exceeds isize::MAX bytes"
+            ),
+            ArrayError::Layout(err) => write!(f, "{err}"),
+            ArrayError::AllocFailed { layout } => write!(
+                f, "failed to allocate {} bytes aligned to {}", layout.size(), layout.align()
+            ),
+            ArrayError::LengthMismatch { expected, found } => write!(
+                f, "length mismatch: expected {expected}, found {found}"
+            ),
+            ArrayError::IndexOutOfBounds { index, len } => write!(
+                f, "index out of bounds: the len is {len} but the index is {index}"
+            ),
+            ArrayError::IteratorExhausted { wanted, got } => write!(
+                f, "iterator exhausted: wanted {wanted} items, got {got}"
+            ),
+            ArrayError::DuplicateIndex { index } => write!(
+                f, "index {index} is given more than once"
+            ),
+            ArrayError::ShapeOverflow => write!(
+                f, "the number of elements of the shape exceeds usize::MAX"
+            ),
+            ArrayError::SliceOutOfBounds { axis, start, end, len } => write!(
+                f, "slice {start}..{end} is out of bounds of the axis {axis} of length {len}"
+            ),
+            ArrayError::AxisIndexOutOfBounds { axis, index, len } => write!(
+                f, "index {index} is out of bounds of the axis {axis} of length {len}"
+            ),
+            ArrayError::TooManyAxes { ndim, found } => write!(
+                f, "the slice takes {found} axes, but the array has only {ndim}"
+            ),
+            ArrayError::ZeroStep { axis } => write!(
+                f, "the step of the axis {axis} is zero"
+            ),
+            ArrayError::ShapeMismatch { left, right } => write!(
+                f, "the shapes {left:?} and {right:?} do not match for broadcasting"
+            ),
+        }
+    }
+}
+
+
+impl Error for ArrayError {
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrayError::LayoutOverflow { source, .. } => Some(source),
+            ArrayError::Layout(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
-/// Used ass a error Parameter in [`Array`](crate::Array) operations.
-#[derive(Debug)]
-pub struct ArrayError(pub String);
 
 impl From<LayoutError> for ArrayError {
 
     #[inline]
     fn from(err: LayoutError) -> Self {
-        ArrayError(err.to_string())
+        ArrayError::Layout(err)
     }
 }
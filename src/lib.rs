@@ -23,12 +23,19 @@
 //!
 //! let arr1: Array<i32> = Array::new(10).expect("cant' create new array");
 //!
-//! let mut vec = vec![1,2,3];
+//! let mut arr2 = Array::<String>::new_uninit(2).expect("cant' create new array");
+//! arr2.write(0, "a".to_string());
+//! arr2.write(1, "b".to_string());
+//! let arr2: Array<String> = unsafe { arr2.assume_init() };
+//!
+//! let mut vec = std::mem::ManuallyDrop::new(vec![1,2,3]);
 //! let ptr = vec.as_mut_ptr();
 //! let size = vec.len();
-//! let arr2: Array<i32> = unsafe { Array::from_pointer(ptr, size) };
+//! let arr3: Array<i32> = unsafe { Array::from_pointer(ptr, size) };
+//!
+//! let arr4: Array<i32> = vec![1,2,3].into();
 //!
-//! let arr3: Array<i32> = vec.into();
+//! let grid: Array<(usize, usize)> = Array::from_fn(6, |i| (i % 3, i / 3));
 //!
 //! ```
 //!
@@ -85,6 +92,10 @@
 //! arr[0] = 17;
 //! assert_eq!(arr[0], 17);
 //!
+//! // sub-slices by ranges
+//! arr[1..].fill(0);
+//! assert_eq!(arr[..2], [17, 0]);
+//!
 //! ```
 //!
 //! Unsafe access:
@@ -112,18 +123,135 @@
 //!
 //! ```
 //!
+//! ## Views
+//!
+//! Strided views see every k-th element, a reversed range or one channel
+//! of interleaved data without copying:
+//!
+//! ```rust
+//! use runtime_sized_array::Array;
+//!
+//! let mut samples: Array<i32> = vec![1, -1, 2, -2, 3, -3].into();
+//!
+//! let left = samples.view().step_by(2);
+//! assert_eq!(left, [1, 2, 3]);
+//! assert_eq!(left.rev().to_array(), [3, 2, 1]);
+//!
+//! for x in samples.view_mut().slice(1..).step_by(2) {
+//!     *x = 0;
+//! }
+//! assert_eq!(samples, [1, 0, 2, 0, 3, 0]);
+//! ```
+//!
+//! ## Matrices
+//!
+//! `Array2` keeps a runtime-shaped matrix in one flat array,
+//! in the row-major (C) or the column-major (Fortran) order:
+//!
+//! ```rust
+//! use runtime_sized_array::{Array, Array2, Order};
+//!
+//! let mut matrix = Array2::from_fn(2, 3, |i, j| 10 * i + j);
+//! assert_eq!(matrix[(1, 2)], 12);
+//! assert_eq!(matrix.col(1), [1, 11]);
+//!
+//! for row in matrix.iter_rows_mut() {
+//!     row.into_iter().for_each(|x| *x += 1);
+//! }
+//!
+//! let flat: Array<usize> = matrix.into_order(Order::ColumnMajor).into_array();
+//! assert_eq!(flat, [1, 11, 2, 12, 3, 13]);
+//! ```
+//!
+//! ## Multi-dimensional arrays
+//!
+//! `ArrayD` takes its number of dimensions at run time, e.g. from a config file.
+//! Reordered axes and sub-arrays are strided views, which do not copy:
+//!
+//! ```rust
+//! use runtime_sized_array::ArrayD;
+//!
+//! let shape = vec![2, 3, 4];
+//! let mut grid = ArrayD::from_fn(&shape, |index| index.iter().sum::<usize>());
+//! assert_eq!(grid[[1, 2, 3]], 6);
+//!
+//! let plane = grid.index_axis(0, 1);
+//! assert_eq!(plane.shape(), &[3, 4]);
+//! assert_eq!(grid.transpose()[[3, 2, 1]], 6);
+//!
+//! grid.reshape(&[6, 4]).unwrap();
+//! let flat = grid.into_array();
+//! assert_eq!(flat.size(), 24);
+//! ```
+//!
+//! The `s!` macro cuts blocks out of `Array2` and `ArrayD` without copying:
+//! ranges with optional steps, single indices, which drop the axis, and `NewAxis`:
+//!
+//! ```rust
+//! use runtime_sized_array::{s, Array2, NewAxis};
+//!
+//! let matrix = Array2::from_fn(4, 5, |i, j| 10 * i + j);
+//!
+//! let block = matrix.slice(s![1..3, ..;-2]).unwrap();
+//! assert_eq!(block.shape(), &[2, 3]);
+//! assert_eq!(block[[0, 0]], 14);
+//!
+//! let col = matrix.slice(s![.., 2, NewAxis]).unwrap();
+//! assert_eq!(col.shape(), &[4, 1]);
+//!
+//! assert!(matrix.slice(s![4]).is_err());
+//! ```
+//!
+//! ## Broadcasting
+//!
+//! The arithmetic operators between `Array`, `Array2`, `ArrayD` and `ArrayViewD`
+//! work elementwise by NumPy's broadcasting rules: the axes are aligned at the end,
+//! the axes of length one are stretched. Mismatching shapes panic with
+//! `ArrayError::ShapeMismatch`, which `broadcast_to` and `zip_with` return instead:
+//!
+//! ```rust
+//! use runtime_sized_array::{Array, Array2, ArrayD};
+//!
+//! let matrix = Array2::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+//! let bias: Array<f64> = vec![0.5, 0.5, 0.5].into();
+//! let shifted: ArrayD<f64> = &matrix + &bias;
+//! assert_eq!(shifted[[1, 2]], 12.5);
+//!
+//! let mut grid = ArrayD::from(matrix);
+//! let scale = ArrayD::from_fn(&[2, 1], |index| (index[0] + 1) as f64);
+//! grid *= &scale;
+//! assert_eq!(grid.as_slice(), &[0.0, 1.0, 2.0, 20.0, 22.0, 24.0]);
+//!
+//! assert!(bias.broadcast_to(&[3, 2]).is_err());
+//! ```
+//!
 //!
 
 
 
 
-#![feature(ptr_const_cast)]
-#![feature(rustc_attrs)]
+// custom diagnostics need the nightly compiler
+#![cfg_attr(feature = "nightly", feature(rustc_attrs))]
+#![cfg_attr(feature = "nightly", allow(internal_features))]
 
 mod array;
+mod array2;
 mod array_iters;
+mod array_views;
+mod arrayd;
+mod arrayd_ops;
+mod arrayd_views;
+mod backing;
 mod error;
+mod slicing;
+mod zeroable;
 
 pub use array::Array;
+pub use array2::{Array2, Order};
+pub use arrayd::ArrayD;
 pub use array_iters::{Iter, IterMut, IntoIter};
-pub use error::ArrayError;
\ No newline at end of file
+pub use array_views::{ArrayView, ArrayViewMut, Lanes, LanesMut, ViewIter, ViewIterMut};
+pub use arrayd_views::{ArrayViewD, ArrayViewMutD, IndexedIter, ViewIterD, ViewIterMutD};
+pub use error::ArrayError;
+pub use slicing::{AxisSlice, NewAxis};
+pub use zeroable::Zeroable;
\ No newline at end of file
@@ -0,0 +1,153 @@
+//! Provides the [`s!`](crate::s) macro and the arguments of the `slice` methods
+//! of the multi-dimensional arrays
+
+use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+
+/// Marker of a new axis of length one in the [`s!`](crate::s) macro.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::{s, ArrayD, NewAxis};
+///
+/// let arr = ArrayD::from_fn(&[3], |index| index[0]);
+/// let col = arr.slice(s![.., NewAxis]).unwrap();
+/// assert_eq!(col.shape(), &[3, 1]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NewAxis;
+
+
+/// What the `slice` methods of the multi-dimensional arrays take from one axis.
+///
+/// Usually made by the [`s!`](crate::s) macro from ranges, indices and [`NewAxis`].
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::{s, AxisSlice, NewAxis};
+///
+/// assert_eq!(s![1..4;2, 0, NewAxis], &[
+///     AxisSlice::Range { start: 1, end: Some(4), step: 2 },
+///     AxisSlice::Index(0),
+///     AxisSlice::NewAxis,
+/// ]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AxisSlice {
+    /// Every `step`-th element of the range `start..end`, where `None` is the end of the axis.
+    ///
+    /// A negative `step` goes backwards, starting from the last element of the range.
+    Range { start: usize, end: Option<usize>, step: isize },
+    /// A single element, the axis is removed.
+    Index(usize),
+    /// A new axis of length one, which does not take any axis of the array.
+    NewAxis,
+}
+
+
+impl AxisSlice {
+
+    /// Every `step`-th element of the `range`.
+    ///
+    /// A negative `step` goes backwards, starting from the last element of the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use runtime_sized_array::AxisSlice;
+    ///
+    /// assert_eq!(AxisSlice::range(2..=4, -1), AxisSlice::Range { start: 2, end: Some(5), step: -1 });
+    /// ```
+    pub fn range<R: RangeBounds<usize>>(range: R, step: isize) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end.saturating_add(1)),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        AxisSlice::Range { start, end, step }
+    }
+}
+
+
+impl From<usize> for AxisSlice {
+
+    #[inline]
+    fn from(index: usize) -> Self {
+        AxisSlice::Index(index)
+    }
+}
+
+
+impl From<NewAxis> for AxisSlice {
+
+    #[inline]
+    fn from(_: NewAxis) -> Self {
+        AxisSlice::NewAxis
+    }
+}
+
+
+macro_rules! impl_from_range {
+    ($($range:ty),*) => {$(
+        impl From<$range> for AxisSlice {
+
+            #[inline]
+            fn from(range: $range) -> Self {
+                AxisSlice::range(range, 1)
+            }
+        }
+    )*};
+}
+
+impl_from_range!(
+    Range<usize>, RangeInclusive<usize>, RangeFrom<usize>,
+    RangeTo<usize>, RangeToInclusive<usize>, RangeFull
+);
+
+
+/// Makes a reference to the array of [`AxisSlice`]s
+/// for the `slice` methods of the multi-dimensional arrays.
+///
+/// Takes a comma-separated list, one item for each axis:
+/// * a range (`a..b`, `a..`, `..b`, `a..=b`, `..`) takes the elements in the range,
+/// * a range followed by `;step` takes every `step`-th of them,
+///   a negative `step` goes backwards from the end of the range,
+/// * an index takes one element and removes the axis,
+/// * [`NewAxis`](crate::NewAxis) inserts a new axis of length one.
+///
+/// The axes, which are not mentioned at the end, are taken whole.
+///
+/// # Example
+///
+/// ```
+/// use runtime_sized_array::{s, ArrayD, NewAxis};
+///
+/// let arr = ArrayD::from_fn(&[4, 5, 6], |index| index.to_vec());
+/// let view = arr.slice(s![1..3, ..;-2, 4, NewAxis]).unwrap();
+/// assert_eq!(view.shape(), &[2, 3, 1]);
+/// assert_eq!(view[[0, 0, 0]], [1, 4, 4]);
+/// assert_eq!(view[[1, 2, 0]], [2, 0, 4]);
+/// ```
+#[macro_export]
+macro_rules! s {
+    (@elem $range:expr ; $step:expr) => {
+        $crate::AxisSlice::range($range, $step)
+    };
+    (@elem $elem:expr) => {
+        <$crate::AxisSlice as ::std::convert::From<_>>::from($elem)
+    };
+    () => {
+        &[] as &[$crate::AxisSlice; 0]
+    };
+    ($($elem:expr $(; $step:expr)?),* $(,)?) => {
+        &[$($crate::s!(@elem $elem $(; $step)?)),*]
+    };
+}
+
@@ -0,0 +1,43 @@
+//! Provides the [`Zeroable`] marker trait
+
+use std::mem::MaybeUninit;
+
+
+/// Marker for types, for which the all-zero bit pattern is a valid value.
+///
+/// [`Array::new`](crate::Array::new) is available only for such types,
+/// because it hands out the freshly allocated (zeroed) memory as initialized elements.
+/// For any other type use [`Array::new_uninit`](crate::Array::new_uninit).
+///
+/// # Safety
+///
+/// Implementing this trait for a type, whose all-zero bit pattern is not a valid value
+/// (like references, [`Box`] or [`NonZeroU8`](std::num::NonZeroU8)), is undefined behaviour.
+#[cfg_attr(feature = "nightly", rustc_on_unimplemented(
+    message = "`{Self}` can not be zero-initialized",
+    label = "`{Self}` does not implement `Zeroable`",
+    note = "use `Array::new_uninit`, `Array::from_default` or `Array::filled` for such types"
+))]
+pub unsafe trait Zeroable {}
+
+
+macro_rules! impl_zeroable {
+    ($($t:ty),*) => {
+        $( unsafe impl Zeroable for $t {} )*
+    };
+}
+
+impl_zeroable!(
+    (), bool, char,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64
+);
+
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+
+unsafe impl<T> Zeroable for *const T {}
+
+unsafe impl<T> Zeroable for *mut T {}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
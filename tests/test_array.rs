@@ -1,15 +1,20 @@
+use std::error::Error;
 use runtime_sized_array::{Array, ArrayError};
 
-#[deprecated]
-#[inline]
-fn testable() -> Array<i32> {
-    let mut vec = vec![1,2,3];
-    vec.into()
+#[test]
+fn assume_init() {
+    let mut arr = Array::<String>::new_uninit(2).unwrap();
+    arr.write(0, "a".to_string());
+    arr.write(1, "b".to_string());
+    let arr: Array<String> = unsafe { arr.assume_init() };
+    assert_eq!(arr[0], "a");
+    assert_eq!(arr[1], "b");
 }
 
+
 #[test]
 fn as_mut_ptr() {
-    let mut arr: Array<i32> = vec![1,2,4].into();
+    let arr: Array<i32> = vec![1,2,4].into();
     let ptr = arr.as_mut_ptr();
     unsafe {
         for i in 0..arr.size() {
@@ -21,7 +26,7 @@ fn as_mut_ptr() {
 
 #[test]
 fn as_ptr() {
-    let mut arr: Array<i32> = vec![1,2,4].into();
+    let arr: Array<i32> = vec![1,2,4].into();
     let ptr = arr.as_ptr();
     unsafe {
         for i in 0..arr.size() {
@@ -31,17 +36,97 @@ fn as_ptr() {
 }
 
 
+#[test]
+fn from_borrowed_pointer() {
+    let mut buffer = [1, 2, 3];
+    {
+        let mut arr: Array<i32> = unsafe {
+            Array::from_borrowed_pointer(buffer.as_mut_ptr(), buffer.len())
+        };
+        arr[0] = 5;
+    }
+    assert_eq!(buffer, [5, 2, 3]);
+}
+
+
+#[test]
+fn filled() {
+    let arr = Array::filled(3, "a".to_string());
+    assert_eq!(&*arr, &["a", "a", "a"]);
+    assert_eq!(Array::filled(0, 1).size(), 0);
+}
+
+
+#[test]
+fn from_default() {
+    let arr: Array<String> = Array::from_default(2);
+    assert_eq!(&*arr, &["", ""]);
+}
+
+
+#[test]
+fn from_elem() {
+    let arr = Array::from_elem(4, 0_u8);
+    assert_eq!(&*arr, &[0; 4]);
+    let arr = Array::from_elem(2, vec![1]);
+    assert_eq!(&*arr, &[vec![1], vec![1]]);
+}
+
+
+#[test]
+fn from_foreign_pointer() {
+    let mut vec = std::mem::ManuallyDrop::new(vec![1, 2, 3]);
+    let (ptr, size, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+    let arr: Array<i32> = unsafe {
+        Array::from_foreign_pointer(ptr, size, move |ptr, size| {
+            drop(Vec::from_raw_parts(ptr, size, capacity))
+        })
+    };
+    assert_eq!(arr[2], 3);
+}
+
+
+#[test]
+fn from_exact_iter() {
+    let arr = Array::from_exact_iter([1, 2, 3].iter().rev());
+    assert_eq!(&*arr, &[&3, &2, &1]);
+    let arr = Array::from_exact_iter(vec!["a".to_string()]);
+    assert_eq!(&*arr, &["a"]);
+}
+
+
+#[test]
+fn from_fn() {
+    let (width, height) = (3, 2);
+    let grid = Array::from_fn(width * height, |i| (i % width, i / width));
+    assert_eq!(&*grid, &[(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+}
+
+
 #[test]
 fn from_pointer() {
-    let mut vec = vec![1,2,3];
+    let mut vec = std::mem::ManuallyDrop::new(vec![1,2,3]);
     let ptr = vec.as_mut_ptr();
     let size = vec.len();
     unsafe {
         let arr: Array<i32> = Array::from_pointer(ptr, size);
+        assert_eq!(arr[2], 3);
     }
 }
 
 
+#[test]
+fn from_vec_pointer() {
+    let mut vec = std::mem::ManuallyDrop::new(Vec::with_capacity(10));
+    vec.extend_from_slice(&[1, 2, 3]);
+    let arr: Array<i32> = unsafe {
+        Array::from_vec_pointer(vec.as_mut_ptr(), vec.len(), vec.capacity())
+    };
+    assert_eq!(arr.size(), 3);
+    assert_eq!(arr[1], 2);
+}
+
+
 #[test]
 fn get() {
     let arr: Array<i32> = vec![1, 2, 4].into();
@@ -51,6 +136,45 @@ fn get() {
 }
 
 
+#[test]
+fn get_checked() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    assert_eq!(arr.get_checked(2), Ok(&4));
+    assert_eq!(arr.get_checked(3), Err(ArrayError::IndexOutOfBounds { index: 3, len: 3 }));
+}
+
+
+#[test]
+fn get_checked_mut() {
+    let mut arr: Array<i32> = vec![1, 2, 4].into();
+    *arr.get_checked_mut(0).unwrap() = 7;
+    assert_eq!(arr, [7, 2, 4]);
+    assert_eq!(arr.get_checked_mut(5), Err(ArrayError::IndexOutOfBounds { index: 5, len: 3 }));
+}
+
+
+#[test]
+fn get_many_mut() {
+    let mut arr: Array<i32> = vec![1, 2, 4, 8].into();
+    let [a, b, c] = arr.get_many_mut([3, 0, 1]).unwrap();
+    *a += *b + *c;
+    assert_eq!(arr, [1, 2, 4, 11]);
+
+    let [] = arr.get_many_mut([]).unwrap();
+    assert_eq!(arr.get_many_mut([0, 4]).err(), Some(ArrayError::IndexOutOfBounds { index: 4, len: 4 }));
+    assert_eq!(arr.get_many_mut([2, 1, 2]).err(), Some(ArrayError::DuplicateIndex { index: 2 }));
+}
+
+
+#[test]
+fn get_many_unchecked_mut() {
+    let mut arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let [b, a] = unsafe { arr.get_many_unchecked_mut([1, 0]) };
+    std::mem::swap(a, b);
+    assert_eq!(arr, ["b", "a"]);
+}
+
+
 #[test]
 fn get_mut() {
     let mut arr: Array<i32> = vec![1,2,4].into();
@@ -79,6 +203,16 @@ fn get_ptr() {
 }
 
 
+#[test]
+fn into_boxed_slice() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    let ptr = arr.as_ptr();
+    let boxed = arr.into_boxed_slice();
+    assert_eq!(boxed.as_ptr(), ptr);
+    assert_eq!(&*boxed, &[1, 2, 4]);
+}
+
+
 #[test]
 fn into_vec() {
     let vec = vec![1,2,4];
@@ -89,7 +223,7 @@ fn into_vec() {
 
 #[test]
 fn iter() {
-    let mut arr: Array<i32> = vec![1,2,4].into();
+    let arr: Array<i32> = vec![1,2,4].into();
     let mut iterator = arr.iter();
 
     assert_eq!(iterator.next(), Some(&1));
@@ -115,6 +249,50 @@ fn new() {
     assert!(arr.is_ok());
     let arr = arr.unwrap();
     assert_eq!(arr.size(), 5);
+    for i in 0..5 {
+        assert_eq!(arr[i], 0);
+    }
+}
+
+
+#[test]
+fn new_uninit() {
+    let arr = Array::<String>::new_uninit(5);
+    assert!(arr.is_ok());
+    assert_eq!(arr.unwrap().size(), 5);
+}
+
+
+#[test]
+fn new_uninit_too_large() {
+    // invalid layout
+    assert!(Array::<u64>::new_uninit(usize::MAX).is_err());
+    // valid layout, but the memory can't be allocated
+    assert!(Array::<u8>::new_uninit(isize::MAX as usize).is_err());
+}
+
+
+#[test]
+fn new_zeroed() {
+    let arr = Array::<u64>::new_zeroed(3).unwrap();
+    let arr = unsafe { arr.assume_init() };
+    assert_eq!(&*arr, &[0, 0, 0]);
+}
+
+
+#[test]
+fn replace() {
+    let mut arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    assert_eq!(arr.replace(1, "c".to_string()), "b");
+    assert_eq!(arr, ["a", "c"]);
+}
+
+
+#[test]
+#[should_panic(expected = "the len is 2 but the index is 2")]
+fn replace_out_of_bounds() {
+    let mut arr: Array<i32> = vec![1, 2].into();
+    arr.replace(2, 0);
 }
 
 
@@ -127,6 +305,19 @@ fn set() {
 }
 
 
+#[test]
+fn set_checked() {
+    let mut arr: Array<String> = vec!["a".to_string()].into();
+    assert_eq!(arr.set_checked(0, "b".to_string()), Ok("a".to_string()));
+    assert_eq!(arr, ["b"]);
+
+    let (err, value) = arr.set_checked(1, "c".to_string()).unwrap_err();
+    assert_eq!(err, ArrayError::IndexOutOfBounds { index: 1, len: 1 });
+    assert_eq!(value, "c");
+    assert_eq!(arr, ["b"]);
+}
+
+
 #[test]
 fn size() {
     let arr: Array<i32> = vec![1,2,4].into();
@@ -136,6 +327,46 @@ fn size() {
 }
 
 
+#[test]
+fn split_at_mut_array() {
+    let mut arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    let (mut left, mut right) = arr.split_at_mut_array(2);
+    assert_eq!((left.size(), right.size()), (2, 3));
+    std::mem::swap(&mut left[0], &mut right[2]);
+    assert_eq!(arr, [5, 2, 3, 4, 1]);
+
+    let (left, right) = arr.split_at_mut_array(5);
+    assert_eq!(left.size(), 5);
+    assert!(right.is_empty());
+}
+
+
+#[test]
+#[should_panic(expected = "split index 6 is out of bounds of the size 5")]
+fn split_at_mut_array_out_of_bounds() {
+    let mut arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    arr.split_at_mut_array(6);
+}
+
+
+#[test]
+fn swap_values() {
+    let mut arr: Array<i32> = vec![1, 2, 4].into();
+    arr.swap_values(0, 2);
+    assert_eq!(arr, [4, 2, 1]);
+    arr.swap_values(1, 1);
+    assert_eq!(arr, [4, 2, 1]);
+}
+
+
+#[test]
+#[should_panic(expected = "the len is 3 but the index is 3")]
+fn swap_values_out_of_bounds() {
+    let mut arr: Array<i32> = vec![1, 2, 4].into();
+    arr.swap_values(0, 3);
+}
+
+
 #[test]
 fn take_from_iter() {
     let mut iter = vec![0,1,2,3,4,5].into_iter();
@@ -147,6 +378,205 @@ fn take_from_iter() {
 }
 
 
+#[test]
+#[should_panic]
+fn take_from_iter_exhausted() {
+    let mut iter = vec![0, 1].into_iter();
+    let _arr: Array<i32> = Array::take_from_iter(&mut iter, 3);
+}
+
+
+#[test]
+fn take_from_iter_or() {
+    let mut iter = vec!["1", "2"].into_iter();
+    let arr: Array<String> = Array::take_from_iter_or(&mut iter, 4, "-".to_string());
+    assert_eq!(&*arr, &["1", "2", "-", "-"]);
+
+    let mut iter = 0..10;
+    let arr: Array<i32> = Array::take_from_iter_or(&mut iter, 3, -1);
+    assert_eq!(&*arr, &[0, 1, 2]);
+    assert_eq!(iter.next(), Some(3));
+}
+
+
+#[test]
+fn take_from_iter_partial() {
+    let mut iter = vec![1, 2].into_iter();
+    let (arr, n) = Array::<i32>::take_from_iter_partial(&mut iter, 4);
+    assert_eq!(n, 2);
+    assert_eq!(&*arr, &[1, 2]);
+
+    let mut iter = 0..10;
+    let (arr, n) = Array::<i32>::take_from_iter_partial(&mut iter, 3);
+    assert_eq!(n, 3);
+    assert_eq!(&*arr, &[0, 1, 2]);
+}
+
+
+#[test]
+fn try_clone() {
+    let arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let clone = arr.try_clone().unwrap();
+    assert_eq!(&*clone, &*arr);
+}
+
+
+#[test]
+fn try_from_iter() {
+    let arr = Array::try_from_iter((1..4).map(|x| x * x)).unwrap();
+    assert_eq!(&*arr, &[1, 4, 9]);
+    let arr = Array::try_from_iter((1..7).filter(|x| x % 2 == 0)).unwrap();
+    assert_eq!(&*arr, &[2, 4, 6]);
+    // wrong exact size hints
+    let arr = Array::try_from_iter(WrongHint { hint: 2, real: 5 }).unwrap();
+    assert_eq!(&*arr, &[4, 3, 2, 1, 0]);
+    let arr = Array::try_from_iter(WrongHint { hint: 4, real: 1 }).unwrap();
+    assert_eq!(&*arr, &[0]);
+
+    let err = Array::try_from_iter(std::iter::repeat(0_u32).take(usize::MAX)).unwrap_err();
+    assert!(matches!(err, ArrayError::LayoutOverflow { elem_size: 4, len: usize::MAX, .. }));
+}
+
+
+/// Reports the exact size `hint`, but yields `real` items.
+struct WrongHint {
+    hint: usize,
+    real: usize,
+}
+
+impl Iterator for WrongHint {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.real = self.real.checked_sub(1)?;
+        Some(self.real)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.hint, Some(self.hint))
+    }
+}
+
+
+#[test]
+fn try_from_vec() {
+    let arr = Array::try_from_vec(vec![1, 2, 4]).unwrap();
+    assert_eq!(&*arr, &[1, 2, 4]);
+}
+
+
+#[test]
+fn try_from_exact_iter() {
+    let arr = Array::try_from_exact_iter(vec![1, 2, 3]).unwrap();
+    assert_eq!(&*arr, &[1, 2, 3]);
+
+    /// Reports the length `len`, but yields `real` items.
+    struct WrongLen {
+        len: usize,
+        real: usize,
+    }
+
+    impl Iterator for WrongLen {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            self.real = self.real.checked_sub(1)?;
+            Some(self.real)
+        }
+    }
+
+    impl ExactSizeIterator for WrongLen {
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    let err = Array::try_from_exact_iter(WrongLen { len: 3, real: 2 }).err();
+    assert_eq!(err, Some(ArrayError::IteratorExhausted { wanted: 3, got: 2 }));
+    let err = Array::try_from_exact_iter(WrongLen { len: 3, real: 5 }).err();
+    assert_eq!(err, Some(ArrayError::LengthMismatch { expected: 3, found: 4 }));
+    // the rest of an endless iterator is not drained
+    let err = Array::try_from_exact_iter(Endless(0)).err();
+    assert_eq!(err, Some(ArrayError::LengthMismatch { expected: 2, found: 3 }));
+}
+
+
+/// Never ends, but reports the length 2.
+struct Endless(u8);
+
+impl Iterator for Endless {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0 = self.0.wrapping_add(1);
+        Some(self.0)
+    }
+}
+
+impl ExactSizeIterator for Endless {
+    fn len(&self) -> usize {
+        2
+    }
+}
+
+
+#[test]
+fn try_from_fn() {
+    let words = ["1", "2", "x", "4"];
+    let arr = Array::try_from_fn(2, |i| -> Result<i32, Box<dyn Error>> { Ok(words[i].parse()?) });
+    assert_eq!(&*arr.unwrap(), &[1, 2]);
+
+    let mut calls = 0;
+    let result = Array::try_from_fn(4, |i| -> Result<i32, Box<dyn Error>> {
+        calls += 1;
+        Ok(words[i].parse()?)
+    });
+    assert!(result.is_err());
+    assert_eq!(calls, 3);
+
+    // the allocation failure is converted to the error type of `f` before calling it
+    let result = Array::<u32>::try_from_fn(usize::MAX, |_| unreachable!());
+    assert!(matches!(result, Err(ArrayError::LayoutOverflow { elem_size: 4, len: usize::MAX, .. })));
+}
+
+
+#[test]
+fn try_filled() {
+    let arr = Array::try_filled(3, "a".to_string()).unwrap();
+    assert_eq!(&*arr, &["a", "a", "a"]);
+    assert_eq!(Array::try_filled(0, 1).unwrap().size(), 0);
+    let err = Array::try_filled(usize::MAX, 1_u64).unwrap_err();
+    assert!(matches!(err, ArrayError::LayoutOverflow { elem_size: 8, len: usize::MAX, .. }));
+}
+
+
+#[test]
+fn try_from_array() {
+    let arr = Array::try_from_array(["a".to_string(), "b".to_string()]).unwrap();
+    assert_eq!(&*arr, &["a", "b"]);
+    let arr = Array::<i32>::try_from_array([]).unwrap();
+    assert_eq!(arr.size(), 0);
+}
+
+
+#[test]
+fn try_from_default() {
+    let arr: Array<String> = Array::try_from_default(2).unwrap();
+    assert_eq!(&*arr, &["", ""]);
+    let err = Array::<String>::try_from_default(usize::MAX).unwrap_err();
+    assert!(matches!(err, ArrayError::LayoutOverflow { len: usize::MAX, .. }));
+}
+
+
+#[test]
+fn try_from_elem() {
+    let arr = Array::try_from_elem(2, vec![1]).unwrap();
+    assert_eq!(&*arr, &[vec![1], vec![1]]);
+    let err = Array::try_from_elem(usize::MAX, 0_u16).unwrap_err();
+    assert!(matches!(err, ArrayError::LayoutOverflow { elem_size: 2, len: usize::MAX, .. }));
+}
+
+
 #[test]
 fn try_get() {
     let arr: Array<i32> = vec![1,2,4].into();
@@ -171,5 +601,40 @@ fn try_set() {
 }
 
 
+#[test]
+fn try_take_from_iter() {
+    let mut iter = vec![0,1,2,3,4,5].into_iter();
+    let arr: Array<i32> = Array::try_take_from_iter(&mut iter, 3).unwrap();
+    assert_eq!(&*arr, &[0, 1, 2]);
+    assert!(Array::<i32>::try_take_from_iter(&mut iter, usize::MAX).is_err());
+
+    let err = Array::<i32>::try_take_from_iter(&mut iter, 5).err();
+    assert_eq!(err, Some(ArrayError::IteratorExhausted { wanted: 5, got: 3 }));
+}
+
+
+#[test]
+fn try_take_from_iter_or() {
+    let mut iter = vec![1, 2].into_iter();
+    let arr: Array<i32> = Array::try_take_from_iter_or(&mut iter, 3, 7).unwrap();
+    assert_eq!(&*arr, &[1, 2, 7]);
+}
+
+
+#[test]
+fn try_take_from_iter_partial() {
+    let mut iter = vec![1, 2, 3].into_iter();
+    let (arr, n) = Array::<i32>::try_take_from_iter_partial(&mut iter, 5).unwrap();
+    assert_eq!(n, 3);
+    assert_eq!(&*arr, &[1, 2, 3]);
+}
 
 
+#[test]
+fn write() {
+    let mut arr = Array::<i32>::new_uninit(2).unwrap();
+    assert_eq!(*arr.write(0, 3), 3);
+    *arr.write(1, 4) += 1;
+    let arr = unsafe { arr.assume_init() };
+    assert_eq!(&*arr, &[3, 5]);
+}
@@ -0,0 +1,267 @@
+use runtime_sized_array::{Array, Array2, ArrayError, Order};
+
+
+fn matrix(rows: usize, cols: usize, order: Order) -> Array2<usize> {
+    Array2::from_fn_with_order(rows, cols, order, |i, j| 10 * i + j)
+}
+
+
+#[test]
+fn col() {
+    let c = matrix(3, 2, Order::RowMajor);
+    let f = matrix(3, 2, Order::ColumnMajor);
+    assert_eq!(c.col(1), [1, 11, 21]);
+    assert_eq!(f.col(1), [1, 11, 21]);
+    assert_eq!((c.col(1).offset(), c.col(1).stride()), (1, 2));
+    assert_eq!(f.col(1).as_slice(), Some(&[1, 11, 21][..]));
+}
+
+
+#[test]
+fn col_mut() {
+    let mut m = matrix(2, 3, Order::RowMajor);
+    m.col_mut(2).iter_mut().for_each(|x| *x = 0);
+    assert_eq!(m.into_array(), [0, 1, 0, 10, 11, 0]);
+}
+
+
+#[test]
+#[should_panic(expected = "column 3 is out of bounds of 3 columns")]
+fn col_out_of_bounds() {
+    matrix(2, 3, Order::RowMajor).col(3);
+}
+
+
+#[test]
+fn debug() {
+    let m = matrix(2, 2, Order::ColumnMajor);
+    assert_eq!(format!("{m:?}"), "[[0, 1], [10, 11]]");
+    let empty: Array2<i32> = Array2::new(0, 3).unwrap();
+    assert_eq!(format!("{empty:?}"), "[]");
+}
+
+
+#[test]
+fn eq() {
+    let c = matrix(2, 3, Order::RowMajor);
+    let f = matrix(2, 3, Order::ColumnMajor);
+    assert_eq!(c, f);
+    assert_eq!(c.clone(), c);
+    assert_ne!(c, matrix(3, 2, Order::RowMajor));
+    // the same elements in memory, but a different shape
+    let flat = Array2::from_array(c.clone().into_array(), 3, 2, Order::RowMajor).unwrap();
+    assert_ne!(c, flat);
+}
+
+
+#[test]
+fn from_array() {
+    let flat: Array<i32> = vec![1, 2, 3, 4, 5, 6].into();
+    let ptr = flat.as_ptr();
+    let m = Array2::from_array(flat, 3, 2, Order::ColumnMajor).unwrap();
+    assert_eq!(m.as_slice().as_ptr(), ptr);
+    assert_eq!(m.row(0), [1, 4]);
+    assert_eq!(m[(2, 1)], 6);
+    let flat: Array<i32> = m.into();
+    assert_eq!(flat.as_ptr(), ptr);
+}
+
+
+#[test]
+fn from_array_errors() {
+    let flat: Array<i32> = vec![1, 2, 3].into();
+    let err = Array2::from_array(flat, 2, 2, Order::RowMajor).err().unwrap();
+    assert_eq!(err, ArrayError::LengthMismatch { expected: 4, found: 3 });
+    let err = Array2::from_array(Array::<i32>::default(), usize::MAX, 2, Order::RowMajor).err().unwrap();
+    assert_eq!(err, ArrayError::ShapeOverflow);
+}
+
+
+#[test]
+fn from_fn() {
+    let m = Array2::from_fn(2, 3, |i, j| (i, j));
+    assert_eq!(m.shape(), (2, 3));
+    assert_eq!(m.order(), Order::RowMajor);
+    assert_eq!(m.as_slice(), &[(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    let m = Array2::from_fn_with_order(2, 3, Order::ColumnMajor, |i, j| (i, j));
+    assert_eq!(m.as_slice(), &[(0, 0), (1, 0), (0, 1), (1, 1), (0, 2), (1, 2)]);
+}
+
+
+#[test]
+fn index() {
+    for order in [Order::RowMajor, Order::ColumnMajor] {
+        let mut m = matrix(3, 4, order);
+        assert_eq!(m[(2, 3)], 23);
+        m[(1, 2)] = 0;
+        assert_eq!(m.try_get(1, 2), Some(&0));
+        assert_eq!(m.try_get(3, 0), None);
+        assert_eq!(m.try_get(0, 4), None);
+        *m.try_get_mut(0, 0).unwrap() = 5;
+        assert_eq!(m.try_get_mut(4, 4), None);
+        assert_eq!(m[(0, 0)], 5);
+    }
+}
+
+
+#[test]
+#[should_panic(expected = "index (1, 3) is out of bounds of the shape (2, 3)")]
+fn index_out_of_bounds() {
+    // the position 1 * 3 + 3 would be in bounds of a larger matrix
+    let _ = matrix(2, 3, Order::RowMajor)[(1, 3)];
+}
+
+
+#[test]
+fn into_order() {
+    let c = matrix(2, 3, Order::RowMajor);
+    let f = c.clone().into_order(Order::ColumnMajor);
+    assert_eq!(f.order(), Order::ColumnMajor);
+    assert_eq!(f.as_slice(), &[0, 10, 1, 11, 2, 12]);
+    assert_eq!(f, c);
+    let ptr = c.as_slice().as_ptr();
+    let same = c.into_order(Order::RowMajor);
+    assert_eq!(same.as_slice().as_ptr(), ptr);
+}
+
+
+#[test]
+fn iter_cols() {
+    let m = matrix(2, 3, Order::ColumnMajor);
+    let mut cols = m.iter_cols();
+    assert_eq!(cols.len(), 3);
+    assert_eq!(cols.next().unwrap(), [0, 10]);
+    assert_eq!(cols.next_back().unwrap(), [2, 12]);
+    assert_eq!(cols.clone().count(), 1);
+    assert_eq!(cols.next().unwrap(), [1, 11]);
+    assert!(cols.next().is_none());
+    assert_eq!(m.iter_rows().nth(1).unwrap(), [10, 11, 12]);
+}
+
+
+#[test]
+fn iter_cols_mut() {
+    let mut m = matrix(2, 3, Order::RowMajor);
+    for (j, col) in m.iter_cols_mut().enumerate() {
+        for x in col {
+            *x += 100 * j;
+        }
+    }
+    assert_eq!(m.as_slice(), &[0, 101, 202, 10, 111, 212]);
+}
+
+
+#[test]
+fn iter_rows() {
+    for order in [Order::RowMajor, Order::ColumnMajor] {
+        let m = matrix(3, 2, order);
+        let rows: Vec<Vec<usize>> = m.iter_rows().map(|row| row.iter().copied().collect()).collect();
+        assert_eq!(rows, [[0, 1], [10, 11], [20, 21]]);
+        assert_eq!(m.iter_rows().next_back().unwrap(), [20, 21]);
+    }
+    let empty: Array2<u8> = Array2::new(3, 0).unwrap();
+    assert_eq!(empty.iter_rows().filter(|row| row.is_empty()).count(), 3);
+    assert_eq!(empty.iter_cols().count(), 0);
+}
+
+
+#[test]
+fn iter_rows_mut() {
+    let mut m = matrix(3, 2, Order::ColumnMajor);
+    let mut rows = m.iter_rows_mut();
+    let mut first = rows.next().unwrap();
+    let mut last = rows.next_back().unwrap();
+    // the rows do not overlap, so they may be used together
+    std::mem::swap(&mut first[0], &mut last[1]);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(m.row(0), [21, 1]);
+    assert_eq!(m.row(2), [20, 0]);
+}
+
+
+#[test]
+fn new() {
+    let m: Array2<i32> = Array2::new(2, 3).unwrap();
+    assert_eq!((m.rows(), m.cols(), m.size()), (2, 3, 6));
+    assert!(m.as_slice().iter().all(|&x| x == 0));
+    let m: Array2<i32> = Array2::new_with_order(2, 3, Order::ColumnMajor).unwrap();
+    assert_eq!(m.order(), Order::ColumnMajor);
+    assert_eq!(Array2::<u8>::new(usize::MAX, 3).err(), Some(ArrayError::ShapeOverflow));
+    assert_eq!(Order::default(), Order::RowMajor);
+}
+
+
+#[test]
+fn row() {
+    let c = matrix(2, 3, Order::RowMajor);
+    let f = matrix(2, 3, Order::ColumnMajor);
+    assert_eq!(c.row(1), [10, 11, 12]);
+    assert_eq!(f.row(1), [10, 11, 12]);
+    assert_eq!(c.row(1).as_slice(), Some(&[10, 11, 12][..]));
+    assert_eq!((f.row(1).offset(), f.row(1).stride()), (1, 2));
+    assert_eq!(f.row(1).rev().to_array(), [12, 11, 10]);
+}
+
+
+#[test]
+fn row_mut() {
+    let mut m = matrix(2, 3, Order::ColumnMajor);
+    let mut row = m.row_mut(0);
+    row[2] = 7;
+    row.iter_mut().for_each(|x| *x += 1);
+    assert_eq!(m.row(0), [1, 2, 8]);
+    assert_eq!(m.row(1), [10, 11, 12]);
+}
+
+
+#[test]
+#[should_panic(expected = "row 2 is out of bounds of 2 rows")]
+fn row_out_of_bounds() {
+    matrix(2, 3, Order::ColumnMajor).row_mut(2);
+}
+
+
+#[test]
+fn transpose() {
+    let m = matrix(2, 3, Order::RowMajor);
+    let ptr = m.as_slice().as_ptr();
+    let t = m.clone().transpose();
+    assert_eq!(t.shape(), (3, 2));
+    assert_ne!(t.as_slice().as_ptr(), ptr);
+    assert_eq!(t[(2, 1)], m[(1, 2)]);
+    assert_eq!(t.row(0), m.col(0));
+    assert_eq!(t.clone().transpose(), m);
+    let t = m.transpose();
+    assert_eq!(t.as_slice().as_ptr(), ptr);
+    assert_eq!(t.order(), Order::ColumnMajor);
+}
+
+
+#[test]
+fn try_from_fn() {
+    let m = Array2::try_from_fn_with_order(2, 2, Order::ColumnMajor, |i, j| Ok::<_, ArrayError>(i * 2 + j));
+    assert_eq!(m.unwrap().as_slice(), &[0, 2, 1, 3]);
+
+    let mut calls = 0;
+    let err = Array2::try_from_fn(3, 3, |i, j| {
+        calls += 1;
+        if (i, j) == (1, 1) { Err(ArrayError::IndexOutOfBounds { index: i, len: j }) } else { Ok(i + j) }
+    });
+    assert_eq!(err.unwrap_err(), ArrayError::IndexOutOfBounds { index: 1, len: 1 });
+    assert_eq!(calls, 5);
+
+    let err: Result<Array2<u8>, ArrayError> = Array2::try_from_fn(usize::MAX, 2, |_, _| unreachable!());
+    assert_eq!(err.unwrap_err(), ArrayError::ShapeOverflow);
+    let err: Result<Array2<u64>, ArrayError> = Array2::try_from_fn(usize::MAX, 1, |_, _| unreachable!());
+    assert!(matches!(err, Err(ArrayError::LayoutOverflow { elem_size: 8, .. })));
+}
+
+
+#[test]
+fn zero_sized() {
+    let mut m: Array2<()> = Array2::new(4, 5).unwrap();
+    assert_eq!(m.iter_cols().map(|col| col.size()).sum::<usize>(), 20);
+    assert_eq!(m.iter_rows_mut().rev().count(), 4);
+    m[(3, 4)] = ();
+    assert_eq!(m.into_order(Order::ColumnMajor).size(), 20);
+}
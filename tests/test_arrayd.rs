@@ -0,0 +1,280 @@
+use runtime_sized_array::{Array, Array2, ArrayD, ArrayError, Order};
+
+
+/// The element at `[i, j, k]` is `ijk` in decimal.
+fn digits(shape: &[usize]) -> ArrayD<usize> {
+    ArrayD::from_fn(shape, |index| index.iter().fold(0, |number, i| 10 * number + i))
+}
+
+
+#[test]
+fn debug() {
+    let arr = digits(&[2, 1, 2]);
+    assert_eq!(format!("{arr:?}"), "[[[0, 1]], [[100, 101]]]");
+    assert_eq!(format!("{:?}", arr.index_axis(1, 0)), "ArrayViewD([[0, 1], [100, 101]])");
+    let scalar = ArrayD::from_fn(&[], |_| 7);
+    assert_eq!(format!("{scalar:?}"), "7");
+    let empty: ArrayD<i32> = ArrayD::new(&[2, 0]).unwrap();
+    assert_eq!(format!("{empty:?}"), "[[], []]");
+    assert_eq!(format!("{:?}", empty.view().iter()), "ViewIterD([])");
+}
+
+
+#[test]
+fn eq() {
+    let arr = digits(&[2, 3]);
+    assert_eq!(arr.clone(), arr);
+    let mut reshaped = arr.clone();
+    reshaped.reshape(&[3, 2]).unwrap();
+    assert_ne!(reshaped, arr);
+    assert_eq!(arr.transpose(), arr.transpose().to_arrayd().view());
+    assert_ne!(arr.transpose(), reshaped.view());
+}
+
+
+#[test]
+fn from_array() {
+    let flat: Array<i32> = (0..24).collect();
+    let ptr = flat.as_ptr();
+    let arr = ArrayD::from_array(flat, &[2, 3, 4]).unwrap();
+    assert_eq!(arr.as_slice().as_ptr(), ptr);
+    assert_eq!(arr.strides(), &[12, 4, 1]);
+    assert_eq!(arr[[1, 2, 3]], 23);
+    let (flat, shape) = arr.into_shape();
+    assert_eq!(flat.as_ptr(), ptr);
+    assert_eq!(shape, [2, 3, 4]);
+}
+
+
+#[test]
+fn from_array_errors() {
+    let flat: Array<i32> = (0..6).collect();
+    let err = ArrayD::from_array(flat, &[4, 2]).err().unwrap();
+    assert_eq!(err, ArrayError::LengthMismatch { expected: 8, found: 6 });
+    let err = ArrayD::from_array(Array::<u8>::default(), &[usize::MAX, 2, 1]).err().unwrap();
+    assert_eq!(err, ArrayError::ShapeOverflow);
+    // an empty axis makes the array empty, whatever the other axes are
+    let empty = ArrayD::from_array(Array::<u8>::default(), &[usize::MAX, 2, 0]).unwrap();
+    assert_eq!(empty.iter_indexed().count(), 0);
+}
+
+
+#[test]
+fn from_conversions() {
+    let flat: Array<i32> = vec![1, 2, 3].into();
+    let arr = ArrayD::from(flat);
+    assert_eq!(arr.shape(), &[3]);
+    assert_eq!(Array::from(arr), [1, 2, 3]);
+
+    let matrix = Array2::from_fn_with_order(2, 3, Order::ColumnMajor, |i, j| 10 * i + j);
+    let arr = ArrayD::from(matrix);
+    assert_eq!(arr, digits(&[2, 3]));
+}
+
+
+#[test]
+fn from_fn() {
+    let mut calls = Vec::new();
+    let arr = ArrayD::from_fn(&[2, 2, 2], |index| calls.push(index.to_vec()));
+    assert_eq!(arr.size(), 8);
+    assert_eq!(calls[..3], [[0, 0, 0], [0, 0, 1], [0, 1, 0]]);
+    assert_eq!(calls[7], [1, 1, 1]);
+
+    let scalar = ArrayD::from_fn(&[], |index| index.len());
+    assert_eq!((scalar.ndim(), scalar.size()), (0, 1));
+    assert_eq!(scalar[[]], 0);
+}
+
+
+#[test]
+fn index() {
+    let mut arr = digits(&[2, 3, 4]);
+    assert_eq!(arr[[1, 2, 3]], 123);
+    assert_eq!(arr[&[0, 1, 2][..]], 12);
+    arr[[1, 0, 0]] = 0;
+    arr[&[1, 1, 1][..]] += 1;
+    assert_eq!(arr.try_get(&[1, 0, 0]), Some(&0));
+    assert_eq!(arr.try_get(&[1, 1, 1]), Some(&112));
+    assert_eq!(arr.try_get(&[2, 0, 0]), None);
+    assert_eq!(arr.try_get(&[0, 0]), None);
+    *arr.try_get_mut(&[0, 0, 0]).unwrap() = 5;
+    assert_eq!(arr.try_get_mut(&[0, 0, 0, 0]), None);
+    assert_eq!(arr.as_slice()[0], 5);
+}
+
+
+#[test]
+#[should_panic(expected = "index [0, 3] is out of bounds of the shape [2, 3]")]
+fn index_out_of_bounds() {
+    // the position 0 * 3 + 3 would be in bounds of the array
+    let _ = digits(&[2, 3])[[0, 3]];
+}
+
+
+#[test]
+#[should_panic(expected = "index [1] is out of bounds of the shape [2, 3]")]
+fn index_wrong_ndim() {
+    let _ = digits(&[2, 3])[[1]];
+}
+
+
+#[test]
+fn index_axis() {
+    let arr = digits(&[2, 3, 4]);
+    let plane = arr.index_axis(2, 3);
+    assert_eq!(plane.shape(), &[2, 3]);
+    assert_eq!(plane.strides(), &[12, 4]);
+    assert_eq!(plane.offset(), 3);
+    assert_eq!(plane[[1, 2]], 123);
+    let line = plane.index_axis(0, 1);
+    assert_eq!(line.iter().copied().collect::<Vec<_>>(), [103, 113, 123]);
+    let point = line.index_axis(0, 2);
+    assert_eq!((point.ndim(), point[[]]), (0, 123));
+}
+
+
+#[test]
+fn index_axis_mut() {
+    let mut arr = digits(&[2, 3]);
+    let mut col = arr.index_axis_mut(1, 1);
+    col[[0]] = 0;
+    for x in col.iter_mut() {
+        *x += 1;
+    }
+    assert_eq!(arr.as_slice(), &[0, 1, 2, 10, 12, 12]);
+}
+
+
+#[test]
+#[should_panic(expected = "index 3 is out of bounds of the axis 1 of length 3")]
+fn index_axis_out_of_bounds() {
+    digits(&[2, 3]).index_axis(1, 3);
+}
+
+
+#[test]
+#[should_panic(expected = "axis 2 is out of bounds of 2 dimensions")]
+fn index_axis_wrong_axis() {
+    digits(&[2, 3]).index_axis(2, 0);
+}
+
+
+#[test]
+fn iter_indexed() {
+    let arr = digits(&[2, 3, 2]);
+    let mut count = 0;
+    for (index, &x) in arr.iter_indexed() {
+        assert_eq!(index.size(), 3);
+        assert_eq!(arr[&index[..]], x);
+        count += 1;
+    }
+    assert_eq!(count, 12);
+
+    let mut iter = arr.permute_axes(&[2, 0, 1]).iter_indexed();
+    assert_eq!(iter.len(), 12);
+    assert_eq!(iter.next(), Some(([0, 0, 0].into(), &0)));
+    assert_eq!(iter.next(), Some(([0, 0, 1].into(), &10)));
+    assert_eq!(iter.nth(2), Some(([0, 1, 1].into(), &110)));
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.last(), Some(([1, 1, 2].into(), &121)));
+}
+
+
+#[test]
+fn new() {
+    let arr: ArrayD<i64> = ArrayD::new(&[3, 4, 5]).unwrap();
+    assert_eq!((arr.ndim(), arr.size()), (3, 60));
+    assert!(arr.as_slice().iter().all(|&x| x == 0));
+    assert_eq!(ArrayD::<u8>::new(&[usize::MAX, 2]).err(), Some(ArrayError::ShapeOverflow));
+    let empty: ArrayD<u8> = ArrayD::new(&[3, 0, 5]).unwrap();
+    assert!(empty.view().is_empty());
+}
+
+
+#[test]
+fn permute_axes() {
+    let arr = digits(&[2, 3, 4]);
+    let view = arr.permute_axes(&[1, 2, 0]);
+    assert_eq!(view.shape(), &[3, 4, 2]);
+    assert_eq!(view.strides(), &[4, 1, 12]);
+    assert_eq!(view[[2, 3, 1]], 123);
+    assert_eq!(view.as_slice(), None);
+    // the inverse permutation brings the original order back
+    let back = view.permute_axes(&[2, 0, 1]);
+    assert_eq!(back.as_slice(), Some(arr.as_slice()));
+    assert_eq!(back, arr.view());
+}
+
+
+#[test]
+#[should_panic(expected = "axes [0, 0] are not a permutation of 0..2")]
+fn permute_axes_duplicate() {
+    digits(&[2, 3]).permute_axes(&[0, 0]);
+}
+
+
+#[test]
+#[should_panic(expected = "axes [1, 0, 2] are not a permutation of 0..2")]
+fn permute_axes_wrong_ndim() {
+    digits(&[2, 3]).permute_axes(&[1, 0, 2]);
+}
+
+
+#[test]
+fn reshape() {
+    let mut arr = digits(&[2, 3]);
+    let ptr = arr.as_slice().as_ptr();
+    arr.reshape(&[3, 1, 2]).unwrap();
+    assert_eq!(arr.shape(), &[3, 1, 2]);
+    assert_eq!(arr.strides(), &[2, 2, 1]);
+    assert_eq!(arr[[2, 0, 0]], 11);
+    assert_eq!(arr.as_slice().as_ptr(), ptr);
+    assert_eq!(arr.reshape(&[7]), Err(ArrayError::LengthMismatch { expected: 7, found: 6 }));
+    assert_eq!(arr.reshape(&[usize::MAX, 3]), Err(ArrayError::ShapeOverflow));
+    assert_eq!(arr.shape(), &[3, 1, 2]);
+}
+
+
+#[test]
+fn transpose() {
+    let arr = digits(&[2, 3, 4]);
+    let t = arr.transpose();
+    assert_eq!(t.shape(), &[4, 3, 2]);
+    assert_eq!(t[[3, 2, 1]], 123);
+    assert_eq!(t.clone().transpose(), arr.view());
+    let copy = t.to_arrayd();
+    assert_eq!(copy.shape(), &[4, 3, 2]);
+    assert_eq!(copy.as_slice()[..3], [0, 100, 10]);
+}
+
+
+#[test]
+fn view_mut() {
+    let mut arr = digits(&[2, 2, 2]);
+    let mut view = arr.view_mut().permute_axes(&[2, 1, 0]);
+    view[[1, 0, 0]] = 0;
+    assert_eq!(view.reborrow().index_axis(0, 0).size(), 4);
+    assert_eq!(view.try_get(&[0, 1, 1]), Some(&110));
+    *view.try_get_mut(&[0, 0, 1]).unwrap() = 7;
+    assert_eq!(view.try_get_mut(&[0, 0, 2]), None);
+    assert_eq!(view.as_mut_slice(), None);
+    for x in &mut view {
+        *x *= 2;
+    }
+    assert_eq!(view.view().iter().count(), 8);
+    assert_eq!(arr.as_slice(), &[0, 0, 20, 22, 14, 202, 220, 222]);
+
+    let mut whole = arr.view_mut();
+    whole.as_mut_slice().unwrap()[1] = 1;
+    assert_eq!(whole.as_slice().unwrap()[..2], [0, 1]);
+}
+
+
+#[test]
+fn zero_sized() {
+    let mut arr: ArrayD<()> = ArrayD::new(&[usize::MAX / 2, 2]).unwrap();
+    assert_eq!(arr.size(), usize::MAX - 1);
+    arr[[usize::MAX / 2 - 1, 1]] = ();
+    assert_eq!(arr.transpose().index_axis(0, 1).size(), usize::MAX / 2);
+    assert_eq!(arr.view_mut().transpose().iter_mut().take(3).count(), 3);
+}
@@ -0,0 +1,152 @@
+use runtime_sized_array::{s, Array, Array2, ArrayD, ArrayError, Order};
+
+
+fn arange(shape: &[usize]) -> ArrayD<i32> {
+    let mut next = 0;
+    ArrayD::from_fn(shape, |_| {
+        next += 1;
+        next - 1
+    })
+}
+
+
+#[test]
+fn add_bias_to_columns() {
+    let matrix = arange(&[2, 3]);
+    let bias: Array<i32> = vec![10, 20, 30].into();
+    let sum = &matrix + &bias;
+    assert_eq!(sum.shape(), &[2, 3]);
+    assert_eq!(sum.as_slice(), &[10, 21, 32, 13, 24, 35]);
+    assert_eq!(&bias + &matrix, sum);
+}
+
+
+#[test]
+fn assign_ops() {
+    let mut matrix = Array2::from_fn_with_order(2, 3, Order::ColumnMajor, |i, j| (10 * i + j) as i32);
+    let scale = ArrayD::from_fn(&[2, 1], |index| index[0] as i32 + 1);
+    matrix *= &scale;
+    assert_eq!(matrix.row(1), [20, 22, 24]);
+    let bias: Array<i32> = vec![1, 2, 3].into();
+    matrix -= &bias;
+    matrix %= &ArrayD::from_fn(&[], |_| 7);
+    assert_eq!(matrix.row(0), [-1, -1, -1]);
+    assert_eq!(matrix.row(1), [5, 6, 0]);
+
+    let mut arr = arange(&[2, 2]);
+    arr += &arr.clone();
+    assert_eq!(arr.as_slice(), &[0, 2, 4, 6]);
+    arr /= &ArrayD::from_fn(&[1], |_| 2);
+    assert_eq!(arr, arange(&[2, 2]));
+
+    // the flat array is one axis on the left too
+    let mut flat: Array<i32> = vec![1, 2, 3].into();
+    flat += &arange(&[3]);
+    assert_eq!(&*flat, &[1, 3, 5]);
+    flat *= &ArrayD::from_fn(&[], |_| 2);
+    assert_eq!(&*flat, &[2, 6, 10]);
+    flat -= &Array::from(vec![1]);
+    assert_eq!(&*flat, &[1, 5, 9]);
+    flat %= &arange(&[4]).view().slice(s![1..]).unwrap();
+    assert_eq!(&*flat, &[0, 1, 0]);
+}
+
+
+#[test]
+#[should_panic(expected = "the shapes [2, 3] and [3] do not match for broadcasting")]
+fn assign_to_flat_does_not_stretch_left() {
+    let mut flat: Array<i32> = vec![1, 2, 3].into();
+    flat += &Array2::from_fn(2, 3, |i, j| (i + j) as i32);
+}
+
+
+#[test]
+#[should_panic(expected = "the shapes [2] and [2, 3] do not match for broadcasting")]
+fn assign_does_not_stretch_left() {
+    let mut matrix = arange(&[2, 3]);
+    let col: Array<i32> = vec![1, 2].into();
+    matrix += &col;
+}
+
+
+#[test]
+fn broadcast_shapes() {
+    let left = arange(&[4, 1, 3]);
+    let right = arange(&[2, 1]);
+    let product = &left * &right;
+    assert_eq!(product.shape(), &[4, 2, 3]);
+    assert_eq!(product[[3, 1, 2]], left[[3, 0, 2]] * right[[1, 0]]);
+    // scalars match anything
+    let scalar = ArrayD::from_fn(&[], |_| 2);
+    assert_eq!((&left * &scalar).shape(), &[4, 1, 3]);
+    assert_eq!((&scalar - &scalar).shape(), &[] as &[usize]);
+    // an axis of length one stretches to the empty one
+    let empty: ArrayD<i32> = ArrayD::new(&[0, 3]).unwrap();
+    assert_eq!((&arange(&[1, 3]) + &empty).shape(), &[0, 3]);
+}
+
+
+#[test]
+fn broadcast_to() {
+    let arr = arange(&[3, 1]);
+    let view = arr.broadcast_to(&[2, 3, 4]).unwrap();
+    assert_eq!(view.shape(), &[2, 3, 4]);
+    assert_eq!(view.strides(), &[0, 1, 0]);
+    assert_eq!(view[[1, 2, 3]], 2);
+    assert_eq!(view.iter().filter(|&&x| x == 1).count(), 8);
+    assert_eq!(view.as_slice(), None);
+    // a stretched view stretches further
+    assert_eq!(view.broadcast_to(&[5, 2, 3, 4]).unwrap().size(), 120);
+}
+
+
+#[test]
+fn broadcast_to_errors() {
+    let arr = arange(&[3, 2]);
+    let err = arr.broadcast_to(&[3, 3]).unwrap_err();
+    assert_eq!(err, ArrayError::ShapeMismatch { left: vec![3, 2].into(), right: vec![3, 3].into() });
+    assert_eq!(err.to_string(), "the shapes [3, 2] and [3, 3] do not match for broadcasting");
+    // the axes are never removed
+    assert!(arr.broadcast_to(&[2]).is_err());
+    let one = arange(&[1]);
+    assert_eq!(one.broadcast_to(&[usize::MAX, 2]).unwrap_err(), ArrayError::ShapeOverflow);
+}
+
+
+#[test]
+fn broadcast_views_and_matrices() {
+    let matrix = Array2::from_fn_with_order(2, 3, Order::ColumnMajor, |i, j| (10 * i + j) as i32);
+    let stretched = matrix.broadcast_to(&[2, 2, 3]).unwrap();
+    assert_eq!(stretched[[1, 1, 2]], 12);
+
+    let arr = arange(&[3, 4]);
+    let col = arr.slice(s![.., 0..1]).unwrap();
+    let diff = &arr.view() - &col;
+    assert_eq!(diff.as_slice(), &[0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]);
+    let sum = &matrix + &matrix;
+    assert_eq!(sum.shape(), &[2, 3]);
+    assert_eq!(sum[[1, 2]], 24);
+    let div = &col / &matrix.slice(s![1, ..1]).unwrap();
+    assert_eq!(div.as_slice(), &[0, 0, 0]);
+}
+
+
+#[test]
+#[should_panic(expected = "the shapes [2, 3] and [2] do not match for broadcasting")]
+fn mismatch_panics() {
+    let matrix = arange(&[2, 3]);
+    let short: Array<i32> = vec![1, 2].into();
+    let _ = &matrix + &short;
+}
+
+
+#[test]
+fn zip_with() {
+    let words: ArrayD<String> = ArrayD::from_fn(&[2, 1], |index| ["a", "b"][index[0]].to_string());
+    let counts = ArrayD::from_fn(&[3], |index| index[0] + 1);
+    let repeated = words.view().zip_with(&counts.view(), |word, &n| word.repeat(n)).unwrap();
+    assert_eq!(repeated[[1, 2]], "bbb");
+    assert_eq!(repeated.size(), 6);
+    let err = counts.view().zip_with(&arange(&[2]).view(), |a, b| *a as i32 + b).unwrap_err();
+    assert_eq!(err, ArrayError::ShapeMismatch { left: vec![3].into(), right: vec![2].into() });
+}
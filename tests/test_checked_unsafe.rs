@@ -0,0 +1,63 @@
+// the unchecked accessors assert the bounds only in these builds
+#![cfg(any(debug_assertions, feature = "checked-unsafe"))]
+
+use std::panic::{self, AssertUnwindSafe};
+use runtime_sized_array::Array;
+
+
+/// Returns the panic message of `f`.
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = panic::catch_unwind(AssertUnwindSafe(f)).err().unwrap();
+    payload.downcast_ref::<String>().cloned().unwrap()
+}
+
+
+#[test]
+#[should_panic(expected = "unchecked access out of bounds: the len is 3 but the index is 3")]
+fn get() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    unsafe { arr.get(3); }
+}
+
+
+#[test]
+#[should_panic(expected = "unchecked access out of bounds: the len is 3 but the index is 7")]
+fn get_mut() {
+    let mut arr: Array<i32> = vec![1, 2, 4].into();
+    unsafe { *arr.get_mut(7) = 0; }
+}
+
+
+#[test]
+#[should_panic(expected = "index 1 is given more than once")]
+fn get_many_unchecked_mut() {
+    let mut arr: Array<i32> = vec![1, 2, 4].into();
+    unsafe { arr.get_many_unchecked_mut([1, 0, 1]); }
+}
+
+
+#[test]
+fn get_ptr() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    // one past the last element is a valid pointer
+    let end = unsafe { arr.get_ptr(3) };
+    assert_eq!(end, arr.as_ptr().wrapping_add(3));
+    let message = panic_message(|| unsafe { arr.get_ptr(4); });
+    assert_eq!(message, "unchecked pointer out of bounds: the len is 3 but the offset is 4");
+}
+
+
+#[test]
+#[should_panic(expected = "unchecked pointer out of bounds: the len is 3 but the offset is 5")]
+fn get_mut_ptr() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    unsafe { arr.get_mut_ptr(5); }
+}
+
+
+#[test]
+#[should_panic(expected = "unchecked access out of bounds: the len is 0 but the index is 0")]
+fn set() {
+    let mut arr: Array<String> = Array::default();
+    unsafe { arr.set(0, "a".to_string()); }
+}
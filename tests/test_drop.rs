@@ -0,0 +1,443 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::mem::ManuallyDrop;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use runtime_sized_array::{Array, Array2, ArrayError, Order};
+
+
+/// Global allocator, which counts the bytes allocated and not yet freed by the current thread.
+///
+/// The counter is thread local, so the tests, running in parallel, do not affect each other.
+struct CountingAllocator;
+
+thread_local! {
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE_BYTES.try_with(|c| c.set(c.get() + layout.size() as isize));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE_BYTES.try_with(|c| c.set(c.get() - layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn live_bytes() -> isize {
+    LIVE_BYTES.with(|c| c.get())
+}
+
+
+/// Increments the shared counter, when dropped.
+struct DropCounter<'a>(&'a Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// Panics, when the `panic_at`-th clone of it is being made.
+struct PanicOnClone<'a> {
+    clones: &'a Cell<usize>,
+    panic_at: usize,
+    _counter: DropCounter<'a>,
+}
+
+impl Clone for PanicOnClone<'_> {
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        if self.clones.get() == self.panic_at {
+            // unlike `panic!` does not run the panic hook, which allocates
+            panic::resume_unwind(Box::new("clone failed"));
+        }
+        PanicOnClone { clones: self.clones, panic_at: self.panic_at, _counter: DropCounter(self._counter.0) }
+    }
+}
+
+fn counters(counter: &Cell<usize>, n: usize) -> Array<DropCounter<'_>> {
+    let mut arr = Array::new_uninit(n).unwrap();
+    for i in 0..n {
+        arr.write(i, DropCounter(counter));
+    }
+    unsafe { arr.assume_init() }
+}
+
+
+#[test]
+fn drop_drops_every_element() {
+    let counter = Cell::new(0);
+    let arr = counters(&counter, 5);
+    assert_eq!(counter.get(), 0);
+    drop(arr);
+    assert_eq!(counter.get(), 5);
+}
+
+
+#[test]
+fn clone_panic_drops_cloned_prefix() {
+    let counter = Cell::new(0);
+    let clones = Cell::new(0);
+    let mut arr = Array::new_uninit(5).unwrap();
+    for i in 0..5 {
+        arr.write(i, PanicOnClone { clones: &clones, panic_at: 3, _counter: DropCounter(&counter) });
+    }
+    let arr = unsafe { arr.assume_init() };
+
+    let before = live_bytes();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| arr.clone()));
+    assert!(result.is_err());
+    drop(result);
+    // two clones were made before the panic
+    assert_eq!(counter.get(), 2);
+    assert_eq!(live_bytes(), before);
+
+    drop(arr);
+    assert_eq!(counter.get(), 7);
+}
+
+
+#[test]
+fn take_from_iter_panic_drops_taken_prefix() {
+    struct PanicOnFrom<'a> {
+        _counter: DropCounter<'a>,
+    }
+
+    impl<'a> From<(usize, &'a Cell<usize>)> for PanicOnFrom<'a> {
+        fn from((i, counter): (usize, &'a Cell<usize>)) -> Self {
+            if i == 3 {
+                panic::resume_unwind(Box::new("conversion failed"));
+            }
+            PanicOnFrom { _counter: DropCounter(counter) }
+        }
+    }
+
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let mut iter = (0..5).map(|i| (i, &counter));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        Array::<PanicOnFrom>::take_from_iter(&mut iter, 5)
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(counter.get(), 3);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn take_from_iter_exhausted_drops_taken_prefix() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let mut iter = (0..2).map(|_| DropCounter(&counter));
+    let result = Array::<DropCounter>::try_take_from_iter(&mut iter, 5);
+    assert!(result.is_err());
+    assert_eq!(counter.get(), 2);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn from_fn_panic_drops_made_prefix() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        Array::from_fn(5, |i| {
+            if i == 3 {
+                panic::resume_unwind(Box::new("constructor failed"));
+            }
+            DropCounter(&counter)
+        })
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(counter.get(), 3);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn try_from_fn_error_drops_made_prefix() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let result = Array::try_from_fn(5, |i| {
+        if i == 2 { Err(ArrayError::IndexOutOfBounds { index: i, len: 5 }) } else { Ok(DropCounter(&counter)) }
+    });
+    assert_eq!(result.err(), Some(ArrayError::IndexOutOfBounds { index: 2, len: 5 }));
+    assert_eq!(counter.get(), 2);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn from_iterator_panic_drops_collected_prefix() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (0..5).map(|i| {
+            if i == 3 {
+                panic::resume_unwind(Box::new("iterator failed"));
+            }
+            DropCounter(&counter)
+        }).collect::<Array<_>>()
+    }));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(counter.get(), 3);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn filled_panic_drops_made_clones() {
+    let counter = Cell::new(0);
+    let clones = Cell::new(0);
+    let before = live_bytes();
+    let value = PanicOnClone { clones: &clones, panic_at: 3, _counter: DropCounter(&counter) };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| Array::filled(5, value)));
+    assert!(result.is_err());
+    drop(result);
+    // two clones and the value itself
+    assert_eq!(counter.get(), 3);
+    assert_eq!(live_bytes(), before);
+
+    let counter = Cell::new(0);
+    let clones = Cell::new(0);
+    let value = PanicOnClone { clones: &clones, panic_at: 0, _counter: DropCounter(&counter) };
+    drop(Array::filled(4, value));
+    // the value is moved into the array, not cloned
+    assert_eq!(clones.get(), 3);
+    assert_eq!(counter.get(), 4);
+}
+
+
+#[test]
+fn drop_deallocates_memory() {
+    let before = live_bytes();
+    let arr: Array<u64> = Array::new(100).unwrap();
+    assert_eq!(live_bytes(), before + 800);
+    drop(arr);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn drop_frees_memory_owned_by_elements() {
+    let before = live_bytes();
+    let arr: Array<String> = vec!["a".repeat(10), "b".repeat(20)].into();
+    drop(arr);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn array2_into_order_moves_every_element_once() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let m = Array2::from_array(counters(&counter, 6), 2, 3, Order::RowMajor).unwrap();
+    let m = m.into_order(Order::ColumnMajor);
+    assert_eq!(counter.get(), 0);
+    drop(m);
+    assert_eq!(counter.get(), 6);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn into_vec_does_not_drop() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let vec = counters(&counter, 3).into_vec();
+    assert_eq!(counter.get(), 0);
+    drop(vec);
+    assert_eq!(counter.get(), 3);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn drop_calls_foreign_deallocator_once() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let before = live_bytes();
+    let mut vec = ManuallyDrop::new(vec![1_u32, 2, 3]);
+    let ptr = vec.as_mut_ptr();
+    let address = ptr as usize;
+    let arr = unsafe {
+        let calls = calls.clone();
+        Array::from_foreign_pointer(ptr, 3, move |p, size| {
+            assert_eq!((p as usize, size), (address, 3));
+            calls.fetch_add(1, Ordering::Relaxed);
+            drop(Vec::from_raw_parts(p, 0, 3));
+        })
+    };
+    drop(arr);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(live_bytes(), before);
+}
+
+
+/// Panics in its destructor, if `panics` is set, after counting the drop.
+struct PanicOnDrop<'a> {
+    _counter: DropCounter<'a>,
+    panics: bool,
+}
+
+impl Drop for PanicOnDrop<'_> {
+    fn drop(&mut self) {
+        if self.panics {
+            panic::resume_unwind(Box::new("drop failed"));
+        }
+    }
+}
+
+
+#[test]
+fn drop_panic_frees_memory() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let arr: Array<PanicOnDrop<'_>> = Array::from_fn(4, |i| {
+        PanicOnDrop { _counter: DropCounter(&counter), panics: i == 1 }
+    });
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(arr)));
+    assert!(result.is_err());
+    drop(result);
+    // the elements after the panicking one are dropped too
+    assert_eq!(counter.get(), 4);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn drop_panic_calls_foreign_deallocator() {
+    let counter = Cell::new(0);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let before = live_bytes();
+    let mut vec = ManuallyDrop::new(vec![
+        PanicOnDrop { _counter: DropCounter(&counter), panics: true },
+        PanicOnDrop { _counter: DropCounter(&counter), panics: false },
+    ]);
+    let arr = unsafe {
+        let calls = calls.clone();
+        Array::from_foreign_pointer(vec.as_mut_ptr(), 2, move |p, size| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            drop(Vec::from_raw_parts(p, 0, size));
+        })
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| drop(arr)));
+    assert!(result.is_err());
+    drop(result);
+    assert_eq!(counter.get(), 2);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn drop_does_not_free_borrowed_memory() {
+    let counter = Cell::new(0);
+    let mut buffer = ManuallyDrop::new(vec![DropCounter(&counter), DropCounter(&counter)]);
+    let before = live_bytes();
+    let arr = unsafe { Array::from_borrowed_pointer(buffer.as_mut_ptr(), 2) };
+    drop(arr);
+    assert_eq!(counter.get(), 2);
+    assert_eq!(live_bytes(), before);
+    // the elements are already dropped by the array
+    unsafe { buffer.set_len(0) };
+    unsafe { ManuallyDrop::drop(&mut buffer) };
+}
+
+
+#[test]
+fn drop_frees_vec_capacity() {
+    let before = live_bytes();
+    let mut vec = ManuallyDrop::new(Vec::<u64>::with_capacity(10));
+    vec.push(1);
+    let arr = unsafe { Array::from_vec_pointer(vec.as_mut_ptr(), 1, vec.capacity()) };
+    drop(arr);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn into_vec_releases_foreign_memory() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut vec = ManuallyDrop::new(vec!["a".to_string(), "b".to_string()]);
+    let arr = unsafe {
+        let calls = calls.clone();
+        Array::from_foreign_pointer(vec.as_mut_ptr(), 2, move |p, _| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            drop(Vec::from_raw_parts(p, 0, 2));
+        })
+    };
+    let vec = arr.into_vec();
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(vec, ["a", "b"]);
+}
+
+
+#[test]
+fn into_iter_drops_remaining_elements_once() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let mut iter = counters(&counter, 5).into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(counter.get(), 2);
+    drop(iter);
+    assert_eq!(counter.get(), 5);
+    assert_eq!(live_bytes(), before);
+}
+
+
+#[test]
+fn into_iter_nth_drops_skipped_elements() {
+    let counter = Cell::new(0);
+    let mut iter = counters(&counter, 6).into_iter();
+    let third = iter.nth(2);
+    assert_eq!(counter.get(), 2);
+    let second_last = iter.nth_back(1);
+    assert_eq!(counter.get(), 3);
+    drop((third, second_last));
+    assert_eq!(counter.get(), 5);
+    drop(iter);
+    assert_eq!(counter.get(), 6);
+}
+
+
+#[test]
+fn from_vec_shrinks_spare_capacity() {
+    let before = live_bytes();
+    let mut vec = Vec::<u64>::with_capacity(100);
+    vec.extend_from_slice(&[1, 2, 3]);
+    let arr: Array<u64> = vec.into();
+    assert_eq!(live_bytes(), before + 24);
+    let boxed = arr.into_boxed_slice();
+    assert_eq!(live_bytes(), before + 24);
+    drop(boxed);
+    assert_eq!(live_bytes(), before);
+
+    let arr: Array<u64> = Vec::with_capacity(10).into();
+    assert_eq!(live_bytes(), before);
+    drop(arr);
+}
+
+
+#[test]
+fn try_into_array_frees_memory() {
+    let counter = Cell::new(0);
+    let before = live_bytes();
+    let array: [DropCounter; 3] = counters(&counter, 3).try_into().ok().unwrap();
+    assert_eq!(live_bytes(), before);
+    assert_eq!(counter.get(), 0);
+    drop(array);
+    assert_eq!(counter.get(), 3);
+}
@@ -0,0 +1,189 @@
+use std::alloc::{GlobalAlloc, Layout, LayoutError, System};
+use std::cell::Cell;
+use std::error::Error;
+use runtime_sized_array::{Array, Array2, ArrayD, ArrayError};
+
+
+/// Global allocator, which fails every allocation of the current thread inside [`failing`].
+///
+/// The flag is thread local, so the tests, running in parallel, do not affect each other.
+struct FailingAllocator;
+
+thread_local! {
+    static FAIL: Cell<bool> = const { Cell::new(false) };
+}
+
+fn fails() -> bool {
+    FAIL.try_with(|fail| fail.get()).unwrap_or(false)
+}
+
+unsafe impl GlobalAlloc for FailingAllocator {
+
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if fails() { std::ptr::null_mut() } else { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if fails() { std::ptr::null_mut() } else { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if fails() { std::ptr::null_mut() } else { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: FailingAllocator = FailingAllocator;
+
+/// Runs `f` with all the allocations failing.
+fn failing<R>(f: impl FnOnce() -> R) -> R {
+    FAIL.with(|fail| fail.set(true));
+    let result = f();
+    FAIL.with(|fail| fail.set(false));
+    result
+}
+
+
+#[test]
+fn alloc_failed() {
+    let layout = Layout::array::<u32>(4).unwrap();
+    let err = failing(|| Array::<u32>::new_uninit(4)).err().unwrap();
+    assert_eq!(err, ArrayError::AllocFailed { layout });
+    let err = failing(|| Array::<u32>::new_zeroed(4)).err().unwrap();
+    assert_eq!(err, ArrayError::AllocFailed { layout });
+
+    let arr: Array<u32> = vec![1, 2, 3, 4].into();
+    assert_eq!(failing(|| arr.try_clone()).err(), Some(ArrayError::AllocFailed { layout }));
+    // shrinking the spare capacity reallocates
+    let mut vec = Vec::with_capacity(8);
+    vec.extend_from_slice(&arr);
+    assert_eq!(failing(|| Array::try_from_vec(vec)).err(), Some(ArrayError::AllocFailed { layout }));
+    // nothing is allocated for empty arrays
+    assert!(failing(|| Array::<u32>::new_uninit(0)).is_ok());
+}
+
+
+#[test]
+fn display() {
+    let err = ArrayError::IndexOutOfBounds { index: 10, len: 3 };
+    assert_eq!(err.to_string(), "index out of bounds: the len is 3 but the index is 10");
+    let source = Layout::array::<u64>(usize::MAX).unwrap_err();
+    let err = ArrayError::LayoutOverflow { elem_size: 8, len: usize::MAX, source };
+    assert_eq!(
+        err.to_string(),
+        format!("layout of {} elements of size 8 exceeds isize::MAX bytes", usize::MAX)
+    );
+    let err = ArrayError::ShapeOverflow;
+    assert_eq!(err.to_string(), "the number of elements of the shape exceeds usize::MAX");
+    let err = ArrayError::SliceOutOfBounds { axis: 1, start: 2, end: 7, len: 5 };
+    assert_eq!(err.to_string(), "slice 2..7 is out of bounds of the axis 1 of length 5");
+    let err = ArrayError::TooManyAxes { ndim: 2, found: 3 };
+    assert_eq!(err.to_string(), "the slice takes 3 axes, but the array has only 2");
+    let err = ArrayError::ZeroStep { axis: 0 };
+    assert_eq!(err.to_string(), "the step of the axis 0 is zero");
+    let err = ArrayError::ShapeMismatch { left: vec![2, 3].into(), right: vec![4].into() };
+    assert_eq!(err.to_string(), "the shapes [2, 3] and [4] do not match for broadcasting");
+}
+
+
+#[test]
+fn duplicate_index() {
+    let mut arr: Array<i32> = vec![1, 2, 3].into();
+    let err = arr.get_many_mut([0, 2, 0]).err().unwrap();
+    assert_eq!(err, ArrayError::DuplicateIndex { index: 0 });
+    assert_eq!(err.to_string(), "index 0 is given more than once");
+}
+
+
+#[test]
+fn error() {
+    fn create() -> Result<Array<u64>, Box<dyn Error>> {
+        Ok(Array::new(usize::MAX)?)
+    }
+    let err = create().err().unwrap();
+    let source = Layout::array::<u64>(usize::MAX).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ArrayError>(),
+        Some(&ArrayError::LayoutOverflow { elem_size: 8, len: usize::MAX, source: source.clone() })
+    );
+    assert_eq!(err.source().unwrap().downcast_ref::<LayoutError>(), Some(&source));
+    assert!(ArrayError::IndexOutOfBounds { index: 1, len: 0 }.source().is_none());
+}
+
+
+#[test]
+fn from_layout_error() {
+    fn layout() -> Result<Layout, ArrayError> {
+        Ok(Layout::array::<u16>(usize::MAX)?)
+    }
+    let err = layout().unwrap_err();
+    let source = Layout::array::<u16>(usize::MAX).unwrap_err();
+    assert_eq!(err, ArrayError::Layout(source.clone()));
+    assert_eq!(err.to_string(), source.to_string());
+    assert_eq!(err.source().unwrap().downcast_ref::<LayoutError>(), Some(&source));
+}
+
+
+#[test]
+fn layout_overflow() {
+    let err = Array::<u32>::new_uninit(usize::MAX / 2).err().unwrap();
+    let source = Layout::array::<u32>(usize::MAX / 2).unwrap_err();
+    assert_eq!(err, ArrayError::LayoutOverflow { elem_size: 4, len: usize::MAX / 2, source });
+    assert_eq!(err.clone(), err);
+}
+
+
+#[test]
+fn allocating_paths_report_layout_overflow() {
+    let len = usize::MAX;
+    fn check<T>(result: Result<T, ArrayError>) {
+        match result.err() {
+            Some(ArrayError::LayoutOverflow { elem_size: 8, len: usize::MAX, source }) => {
+                assert_eq!(source, Layout::array::<u64>(usize::MAX).unwrap_err())
+            }
+            err => panic!("unexpected {err:?}"),
+        }
+    }
+    check(Array::<u64>::new(len));
+    check(Array::<u64>::new_uninit(len));
+    check(Array::<u64>::new_zeroed(len));
+    check(Array::<u64>::try_from_fn(len, |_| Ok(0)));
+    check(Array::try_filled(len, 0_u64));
+    check(Array::<u64>::try_from_default(len));
+    check(Array::try_from_elem(len, 0_u64));
+    check(Array::try_from_iter(std::iter::repeat(0_u64).take(len)));
+    check(Array::try_from_exact_iter((0..len).map(|_| 0_u64)));
+    check(Array::<u64>::try_take_from_iter(&mut std::iter::repeat(0_u64), len));
+    check(Array::try_take_from_iter_or(&mut std::iter::empty::<u64>(), len, 0_u64));
+    check(Array::<u64>::try_take_from_iter_partial(&mut std::iter::empty::<u64>(), len));
+    check(Array2::<u64>::new(len, 1));
+    check(Array2::<u64>::try_from_fn(1, len, |_, _| Ok(0)));
+    check(ArrayD::<u64>::new(&[1, len]));
+}
+
+
+#[test]
+fn allocating_paths_report_alloc_failed() {
+    let layout = Layout::array::<u64>(3).unwrap();
+    fn check<T>(result: Result<T, ArrayError>, layout: Layout) {
+        assert_eq!(result.err(), Some(ArrayError::AllocFailed { layout }));
+    }
+    failing(|| {
+        check(Array::<u64>::try_from_fn(3, |_| Ok(0)), layout);
+        check(Array::try_filled(3, 0_u64), layout);
+        check(Array::<u64>::try_from_default(3), layout);
+        check(Array::try_from_elem(3, 0_u64), layout);
+        check(Array::try_from_array([0_u64; 3]), layout);
+        check(Array::try_from_iter([1_u64, 2, 3]), layout);
+        // without the exact size hint the items are collected into a growing vector
+        let one = Layout::array::<u64>(1).unwrap();
+        check(Array::try_from_iter([1_u64, 2, 3].into_iter().filter(|_| true)), one);
+        check(Array::<u64>::try_take_from_iter(&mut std::iter::repeat(0_u64), 3), layout);
+        check(Array2::<u64>::new(3, 1), layout);
+        check(ArrayD::<u64>::new(&[1, 3]), layout);
+    });
+}
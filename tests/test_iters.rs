@@ -0,0 +1,204 @@
+use runtime_sized_array::Array;
+
+
+fn array() -> Array<String> {
+    vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()].into()
+}
+
+
+#[test]
+fn into_iter_as_slice() {
+    let mut iter = array().into_iter();
+    assert_eq!(iter.as_slice(), &["a", "b", "c", "d"]);
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.as_slice(), &["b", "c"]);
+    iter.as_mut_slice()[0].push('!');
+    assert_eq!(iter.next().unwrap(), "b!");
+}
+
+
+#[test]
+fn into_iter_clone() {
+    let mut iter = array().into_iter();
+    iter.next();
+    let clone = iter.clone();
+    assert_eq!(clone.collect::<Vec<_>>(), ["b", "c", "d"]);
+    assert_eq!(iter.collect::<Vec<_>>(), ["b", "c", "d"]);
+}
+
+
+#[test]
+fn into_iter_debug() {
+    let mut iter = array().into_iter();
+    iter.next();
+    assert_eq!(format!("{iter:?}"), r#"IntoIter(["b", "c", "d"])"#);
+}
+
+
+#[test]
+fn into_iter_double_ended() {
+    let mut iter = array().into_iter();
+    assert_eq!(iter.next_back().unwrap(), "d");
+    assert_eq!(iter.next().unwrap(), "a");
+    assert_eq!(iter.rev().collect::<Vec<_>>(), ["c", "b"]);
+}
+
+
+#[test]
+fn into_iter_exact_size() {
+    let mut iter = array().into_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.next();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.count(), 3);
+}
+
+
+#[test]
+fn into_iter_fused() {
+    let mut iter = array().into_iter();
+    assert_eq!(iter.by_ref().count(), 4);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+
+#[test]
+fn into_iter_nth() {
+    let mut iter = array().into_iter();
+    assert_eq!(iter.nth(1).unwrap(), "b");
+    assert_eq!(iter.nth_back(0).unwrap(), "d");
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.len(), 0);
+}
+
+
+#[test]
+fn into_iter_last() {
+    assert_eq!(array().into_iter().last().unwrap(), "d");
+}
+
+
+#[test]
+fn iter_as_slice() {
+    let arr = array();
+    let mut iter = arr.iter();
+    iter.next();
+    assert_eq!(iter.as_slice(), &["b", "c", "d"]);
+}
+
+
+#[test]
+fn iter_clone() {
+    let arr = array();
+    let mut iter = arr.iter();
+    iter.next();
+    let clone = iter.clone();
+    assert_eq!(iter.count(), 3);
+    assert_eq!(clone.collect::<Vec<_>>(), ["b", "c", "d"]);
+}
+
+
+#[test]
+fn iter_debug() {
+    let mut arr = array();
+    let mut iter = arr.iter();
+    iter.next();
+    assert_eq!(format!("{iter:?}"), r#"Iter(["b", "c", "d"])"#);
+    let mut iter = arr.iter_mut();
+    iter.next_back();
+    assert_eq!(format!("{iter:?}"), r#"IterMut(["a", "b", "c"])"#);
+}
+
+
+#[test]
+fn iter_double_ended() {
+    let arr = array();
+    let mut iter = arr.iter();
+    assert_eq!(iter.next_back().unwrap(), "d");
+    assert_eq!(iter.next().unwrap(), "a");
+    assert_eq!(iter.rev().collect::<Vec<_>>(), ["c", "b"]);
+    assert_eq!(arr.iter().nth_back(1).unwrap(), "c");
+    assert_eq!(arr.iter().nth_back(4), None);
+}
+
+
+#[test]
+fn iter_exact_size() {
+    let arr = array();
+    let mut iter = arr.iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.next();
+    assert_eq!(iter.len(), 3);
+}
+
+
+#[test]
+fn iter_fused() {
+    let arr = array();
+    let mut iter = arr.iter();
+    assert_eq!(iter.by_ref().count(), 4);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+
+#[test]
+fn iter_mut_double_ended() {
+    let mut arr: Array<i32> = vec![1, 2, 3, 4].into();
+    for (i, x) in arr.iter_mut().rev().enumerate() {
+        *x += i as i32 * 10;
+    }
+    assert_eq!(&*arr, &[31, 22, 13, 4]);
+    let mut iter = arr.iter_mut();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.nth_back(1), Some(&mut 13));
+    assert_eq!(iter.last(), Some(&mut 22));
+}
+
+
+#[test]
+fn iter_mut_into_slice() {
+    let mut arr = array();
+    let mut iter = arr.iter_mut();
+    iter.next();
+    assert_eq!(iter.as_slice(), &["b", "c", "d"]);
+    iter.into_slice()[0].push('!');
+    assert_eq!(arr[1], "b!");
+}
+
+
+#[test]
+fn iter_nth() {
+    let arr = array();
+    let mut iter = arr.iter();
+    assert_eq!(iter.nth(1).unwrap(), "b");
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(arr.iter().last().unwrap(), "d");
+
+    let mut arr = arr;
+    let mut iter = arr.iter_mut();
+    assert_eq!(iter.nth(2).unwrap(), "c");
+    assert_eq!(iter.count(), 1);
+}
+
+
+#[test]
+fn iter_send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: T) {}
+    let mut arr = array();
+    assert_send_sync(arr.iter());
+    assert_send_sync(arr.iter_mut());
+}
+
+
+#[test]
+fn iter_zip_rev() {
+    let a: Array<i32> = vec![1, 2, 3].into();
+    let b: Array<i32> = vec![10, 20, 30].into();
+    let sums: Vec<i32> = a.iter().zip(b.iter()).rev().map(|(x, y)| x + y).collect();
+    assert_eq!(sums, [33, 22, 11]);
+}
@@ -0,0 +1,28 @@
+// the unchecked accessors assert the bounds only in these builds
+#![cfg(any(debug_assertions, feature = "checked-unsafe"))]
+
+// the only test of this binary: it replaces the global panic hook,
+// which would catch the panics of the tests running in parallel
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use runtime_sized_array::Array;
+
+
+/// The file of the location of the last panic.
+static LOCATION: Mutex<Option<String>> = Mutex::new(None);
+
+
+#[test]
+fn location_is_caller() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let file = info.location().map(|location| location.file().to_string());
+        *LOCATION.lock().unwrap() = file;
+    }));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe { arr.get(3); }));
+    panic::set_hook(hook);
+    assert!(result.is_err());
+    assert_eq!(LOCATION.lock().unwrap().as_deref(), Some(file!()));
+}
@@ -0,0 +1,156 @@
+use runtime_sized_array::{s, Array2, ArrayD, ArrayError, ArrayViewD, AxisSlice, NewAxis, Order};
+
+
+/// The element at `[i, j, k]` is `ijk` in decimal.
+fn digits(shape: &[usize]) -> ArrayD<usize> {
+    ArrayD::from_fn(shape, |index| index.iter().fold(0, |number, i| 10 * number + i))
+}
+
+
+fn elements(view: ArrayViewD<'_, usize>) -> Vec<usize> {
+    view.iter().copied().collect()
+}
+
+
+#[test]
+fn macro_items() {
+    assert!(s![].is_empty());
+    assert_eq!(s![2], &[AxisSlice::Index(2)]);
+    assert_eq!(s![NewAxis], &[AxisSlice::NewAxis]);
+    assert_eq!(s![..], &[AxisSlice::Range { start: 0, end: None, step: 1 }]);
+    assert_eq!(s![1..=3, ..2;-1], &[
+        AxisSlice::Range { start: 1, end: Some(4), step: 1 },
+        AxisSlice::Range { start: 0, end: Some(2), step: -1 },
+    ]);
+    let (start, step) = (1, 3);
+    assert_eq!(s![start..;step, start,], &[
+        AxisSlice::Range { start: 1, end: None, step: 3 },
+        AxisSlice::Index(1),
+    ]);
+}
+
+
+#[test]
+fn slice_built_at_run_time() {
+    let arr = digits(&[3, 3, 3]);
+    let info: Vec<AxisSlice> = (0..3).map(|axis| AxisSlice::range(axis.., 1)).collect();
+    let view = arr.slice(&info).unwrap();
+    assert_eq!(view.shape(), &[3, 2, 1]);
+    assert_eq!(view[[2, 1, 0]], 222);
+}
+
+
+#[test]
+fn slice_errors() {
+    let arr = digits(&[2, 3]);
+    assert_eq!(
+        arr.slice(s![.., 1..4]).unwrap_err(),
+        ArrayError::SliceOutOfBounds { axis: 1, start: 1, end: 4, len: 3 }
+    );
+    assert_eq!(
+        arr.slice(&[AxisSlice::Range { start: 2, end: Some(1), step: 1 }]).unwrap_err(),
+        ArrayError::SliceOutOfBounds { axis: 0, start: 2, end: 1, len: 2 }
+    );
+    assert_eq!(
+        arr.slice(s![NewAxis, 0, 3]).unwrap_err(),
+        ArrayError::AxisIndexOutOfBounds { axis: 1, index: 3, len: 3 }
+    );
+    assert_eq!(arr.slice(s![0, 0, 0]).unwrap_err(), ArrayError::TooManyAxes { ndim: 2, found: 3 });
+    assert_eq!(arr.slice(s![..;0]).unwrap_err(), ArrayError::ZeroStep { axis: 0 });
+}
+
+
+#[test]
+fn slice_index() {
+    let arr = digits(&[2, 3, 4]);
+    let view = arr.slice(s![1, .., 2]).unwrap();
+    assert_eq!(view.shape(), &[3]);
+    assert_eq!(elements(view), [102, 112, 122]);
+    let point = arr.slice(s![1, 2, 3]).unwrap();
+    assert_eq!((point.ndim(), point[[]]), (0, 123));
+}
+
+
+#[test]
+fn slice_missing_axes() {
+    let arr = digits(&[2, 3, 4]);
+    let view = arr.slice(s![1]).unwrap();
+    assert_eq!(view.shape(), &[3, 4]);
+    assert_eq!(view, arr.index_axis(0, 1));
+    assert_eq!(arr.slice(s![]).unwrap(), arr.view());
+}
+
+
+#[test]
+fn slice_mut() {
+    let mut arr = digits(&[3, 4]);
+    let mut view = arr.slice_mut(s![1.., ..;-3]).unwrap();
+    assert_eq!(view.shape(), &[2, 2]);
+    view[[0, 0]] = 0;
+    let mut col = view.slice(s![.., 1]).unwrap();
+    col[[1]] = 1;
+    assert_eq!(arr.as_slice(), &[0, 1, 2, 3, 10, 11, 12, 0, 1, 21, 22, 23]);
+    let err = arr.slice_mut(s![3]).err().unwrap();
+    assert_eq!(err.to_string(), "index 3 is out of bounds of the axis 0 of length 3");
+}
+
+
+#[test]
+fn slice_new_axis() {
+    let arr = digits(&[2, 3]);
+    let view = arr.slice(s![NewAxis, .., NewAxis, 1..]).unwrap();
+    assert_eq!(view.shape(), &[1, 2, 1, 2]);
+    assert_eq!(view.strides(), &[0, 3, 0, 1]);
+    assert_eq!(view[[0, 1, 0, 1]], 12);
+    assert_eq!(view.as_slice(), None);
+}
+
+
+#[test]
+fn slice_of_array2() {
+    for order in [Order::RowMajor, Order::ColumnMajor] {
+        let mut matrix = Array2::from_fn_with_order(3, 4, order, |i, j| 10 * i + j);
+        let view = matrix.slice(s![..;-1, 1..3]).unwrap();
+        assert_eq!(view.shape(), &[3, 2]);
+        assert_eq!(elements(view), [21, 22, 11, 12, 1, 2]);
+        let col = matrix.slice(s![.., 3]).unwrap();
+        assert_eq!(col, matrix.slice(s![.., 3..]).unwrap().index_axis(1, 0));
+        matrix.slice_mut(s![1, NewAxis]).unwrap().iter_mut().for_each(|x| *x = 0);
+        assert_eq!(matrix.row(1), [0, 0, 0, 0]);
+        let err = matrix.slice_mut(s![3]).err();
+        assert_eq!(err, Some(ArrayError::AxisIndexOutOfBounds { axis: 0, index: 3, len: 3 }));
+    }
+}
+
+
+#[test]
+fn slice_of_view() {
+    let arr = digits(&[4, 5]);
+    let transposed = arr.transpose();
+    let view = transposed.slice(s![1..4;2, ..;-2]).unwrap();
+    assert_eq!(view.shape(), &[2, 2]);
+    assert_eq!(elements(view.clone()), [31, 11, 33, 13]);
+    let twice = view.slice(s![1, ..;-1]).unwrap();
+    assert_eq!(elements(twice), [13, 33]);
+}
+
+
+#[test]
+fn slice_steps() {
+    let arr = digits(&[1, 10]);
+    let take = |info: &[AxisSlice]| elements(arr.slice(info).unwrap());
+    assert_eq!(take(s![0, ..;3]), [0, 3, 6, 9]);
+    assert_eq!(take(s![0, 2..8;-2]), [7, 5, 3]);
+    assert_eq!(take(s![0, ..=4;-1]), [4, 3, 2, 1, 0]);
+    assert_eq!(take(s![0, 5..5;-1]), []);
+    assert_eq!(take(s![0, 9..;100]), [9]);
+}
+
+
+#[test]
+fn slice_zero_sized() {
+    let arr: ArrayD<()> = ArrayD::new(&[usize::MAX / 4, 4]).unwrap();
+    let view = arr.slice(s![..;-1, NewAxis, 1..;2]).unwrap();
+    assert_eq!(view.shape(), &[usize::MAX / 4, 1, 2]);
+    assert_eq!(view.iter().take(5).count(), 5);
+}
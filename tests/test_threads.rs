@@ -0,0 +1,116 @@
+use std::mem::size_of;
+use std::sync::Arc;
+use std::thread;
+use runtime_sized_array::{
+    Array, Array2, ArrayD, ArrayView, ArrayViewD, ArrayViewMut, ArrayViewMutD, IndexedIter, IntoIter,
+    Lanes, LanesMut, ViewIter, ViewIterD, ViewIterMut, ViewIterMutD,
+};
+
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+
+#[test]
+fn auto_traits() {
+    assert_send::<Array<String>>();
+    assert_sync::<Array<String>>();
+    assert_send::<IntoIter<String>>();
+    assert_sync::<IntoIter<String>>();
+    assert_send::<ArrayViewMut<String>>();
+    assert_sync::<ArrayViewMut<String>>();
+    assert_send::<ArrayView<String>>();
+    assert_sync::<ArrayView<String>>();
+    assert_send::<ViewIter<String>>();
+    assert_send::<ViewIterMut<String>>();
+    assert_send::<Array2<String>>();
+    assert_sync::<Array2<String>>();
+    assert_send::<Lanes<String>>();
+    assert_send::<LanesMut<String>>();
+    assert_send::<ArrayD<String>>();
+    assert_sync::<ArrayD<String>>();
+    assert_send::<ArrayViewD<String>>();
+    assert_sync::<ArrayViewD<String>>();
+    assert_send::<ArrayViewMutD<String>>();
+    assert_sync::<ArrayViewMutD<String>>();
+    assert_send::<ViewIterD<String>>();
+    assert_send::<ViewIterMutD<String>>();
+    assert_send::<IndexedIter<String>>();
+}
+
+
+#[test]
+fn covariance() {
+    fn shorten<'a>(arr: Array<&'static str>) -> Array<&'a str> {
+        arr
+    }
+    let arr = shorten(vec!["a"].into());
+    assert_eq!(arr[0], "a");
+}
+
+
+#[test]
+fn niche() {
+    assert_eq!(size_of::<Option<Array<u8>>>(), size_of::<Array<u8>>());
+}
+
+
+#[test]
+fn move_to_thread() {
+    let arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let joined = thread::spawn(move || arr.iter().map(String::as_str).collect::<String>())
+        .join()
+        .unwrap();
+    assert_eq!(joined, "ab");
+}
+
+
+#[test]
+fn share_between_threads() {
+    let table: Arc<Array<u64>> = Arc::new((0..100).collect::<Vec<_>>().into());
+    let workers: Vec<_> = (0..4)
+        .map(|i| {
+            let table = table.clone();
+            thread::spawn(move || table.iter().skip(i * 25).take(25).sum::<u64>())
+        })
+        .collect();
+    let sum: u64 = workers.into_iter().map(|w| w.join().unwrap()).sum();
+    assert_eq!(sum, 4950);
+}
+
+
+#[test]
+fn scoped_mutation() {
+    let mut arr: Array<u32> = Array::new(8).unwrap();
+    thread::scope(|s| {
+        for (i, chunk) in arr.chunks_mut(2).enumerate() {
+            s.spawn(move || chunk.fill(i as u32));
+        }
+    });
+    assert_eq!(&*arr, &[0, 0, 1, 1, 2, 2, 3, 3]);
+}
+
+
+#[test]
+fn scoped_mutation_of_halves() {
+    let mut arr: Array<u32> = Array::new(6).unwrap();
+    let (left, right) = arr.split_at_mut_array(2);
+    thread::scope(|s| {
+        s.spawn(move || left.into_iter().for_each(|x| *x = 1));
+        s.spawn(move || right.into_iter().for_each(|x| *x = 2));
+    });
+    assert_eq!(arr, [1, 1, 2, 2, 2, 2]);
+}
+
+
+#[test]
+fn scoped_mutation_of_channels() {
+    let mut samples: Array<u32> = Array::new(6).unwrap();
+    let (left, right) = samples.view_mut().split_at(3);
+    thread::scope(|s| {
+        s.spawn(move || left.step_by(2).into_iter().for_each(|x| *x = 1));
+        s.spawn(move || right.rev().step_by(2).into_iter().for_each(|x| *x = 2));
+    });
+    assert_eq!(samples, [1, 0, 1, 2, 0, 2]);
+}
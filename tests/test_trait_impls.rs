@@ -1,7 +1,67 @@
-use std::mem::ManuallyDrop;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use runtime_sized_array::Array;
 
+
+/// Yields `len` items, but reports the exact length `hint`.
+struct WrongHint {
+    len: usize,
+    hint: usize,
+}
+
+impl Iterator for WrongHint {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.hint = self.hint.saturating_sub(1);
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.len)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.hint, Some(self.hint))
+    }
+}
+
+
+#[test]
+fn as_mut() {
+    let mut arr: Array<i32> = vec![1, 2, 3].into();
+    arr.as_mut().reverse();
+    assert_eq!(arr, [3, 2, 1]);
+}
+
+
+#[test]
+fn as_ref() {
+    fn sum(values: impl AsRef<[i32]>) -> i32 {
+        values.as_ref().iter().sum()
+    }
+    let arr: Array<i32> = vec![1, 2, 3].into();
+    assert_eq!(sum(&arr), 6);
+}
+
+
+#[test]
+fn borrow() {
+    let mut map: HashMap<Array<u8>, i32> = HashMap::new();
+    map.insert(b"one".as_slice().into(), 1);
+    map.insert(b"two".as_slice().into(), 2);
+    assert_eq!(map.get(b"two".as_slice()), Some(&2));
+    assert_eq!(map.get(b"three".as_slice()), None);
+
+    let mut set: BTreeSet<Array<i32>> = BTreeSet::new();
+    set.insert(vec![1, 2].into());
+    assert!(set.contains([1, 2].as_slice()));
+}
+
+
 #[test]
 fn clone() {
     let old_arr: Array<i32> = vec![5, 1, 0, 3].into();
@@ -13,6 +73,48 @@ fn clone() {
 }
 
 
+#[test]
+fn clone_deep() {
+    let old_arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let mut new_arr = old_arr.clone();
+    new_arr[0].push('c');
+    assert_eq!(old_arr[0], "a");
+    assert_eq!(new_arr[0], "ac");
+    assert_ne!(old_arr[1].as_ptr(), new_arr[1].as_ptr());
+}
+
+
+#[test]
+fn clone_from() {
+    let source: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let mut arr: Array<String> = vec![String::new(), String::new()].into();
+    let ptr = arr.as_ptr();
+    arr.clone_from(&source);
+    assert_eq!(arr.as_ptr(), ptr);
+    assert_eq!(&*arr, &*source);
+
+    let mut arr: Array<String> = Vec::new().into();
+    arr.clone_from(&source);
+    assert_eq!(&*arr, &*source);
+}
+
+
+#[test]
+fn debug() {
+    let arr: Array<&str> = vec!["a", "b"].into();
+    assert_eq!(format!("{arr:?}"), r#"["a", "b"]"#);
+    assert_eq!(format!("{:?}", Array::<i32>::default()), "[]");
+}
+
+
+#[test]
+fn default() {
+    let arr: Array<String> = Array::default();
+    assert_eq!(arr.size(), 0);
+    assert!(arr.is_empty());
+}
+
+
 #[test]
 fn deref() {
     let arr : Array<i32 >= vec![1,2,3].into();
@@ -27,6 +129,40 @@ fn deref_mut() {
 }
 
 
+#[test]
+fn eq() {
+    let arr: Array<i32> = vec![1, 2, 3].into();
+    assert_eq!(arr, arr.clone());
+    assert_ne!(arr, Array::from(vec![1, 2]));
+
+    let strings: Array<String> = vec!["a".to_string()].into();
+    let strs: Array<&str> = vec!["a"].into();
+    assert_eq!(strings, strs);
+}
+
+
+#[test]
+fn eq_other_sequences() {
+    let mut arr: Array<i32> = vec![1, 2, 3].into();
+    let mut vec = vec![1, 2, 3];
+
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!(arr, vec);
+    assert_eq!(arr, vec[..]);
+    assert_eq!(arr, &vec[..]);
+    assert_eq!(arr, &mut vec[..]);
+    assert_ne!(arr, [1, 2]);
+
+    assert_eq!([1, 2, 3], arr);
+    assert_eq!(vec, arr);
+    assert_eq!(vec[..], arr);
+    assert_eq!(&vec[..], arr);
+    assert_eq!(&mut vec[..], arr);
+    arr[0] = 0;
+    assert_ne!(vec, arr);
+}
+
+
 #[test]
 fn from() {
     let source: Vec<i32> = vec![0, 1, 2, 3];
@@ -38,9 +174,83 @@ fn from() {
 }
 
 
+#[test]
+fn from_array() {
+    let arr: Array<String> = ["a".to_string(), "b".to_string()].into();
+    assert_eq!(&*arr, &["a", "b"]);
+    let arr: Array<i32> = [].into();
+    assert_eq!(arr.size(), 0);
+}
+
+
+#[test]
+fn from_box() {
+    let source: Box<[String]> = vec!["a".to_string(), "b".to_string()].into_boxed_slice();
+    let ptr = source.as_ptr();
+    let arr: Array<String> = source.into();
+    assert_eq!(arr.as_ptr(), ptr);
+    assert_eq!(&*arr, &["a", "b"]);
+}
+
+
+#[test]
+fn from_iterator() {
+    let squares: Array<i32> = (1..4).map(|x| x * x).collect();
+    assert_eq!(&*squares, &[1, 4, 9]);
+    let even: Array<i32> = (1..7).filter(|x| x % 2 == 0).collect();
+    assert_eq!(&*even, &[2, 4, 6]);
+    let empty: Array<String> = std::iter::empty().collect();
+    assert_eq!(empty.size(), 0);
+}
+
+
+#[test]
+fn from_iterator_wrong_size_hint() {
+    let arr: Array<usize> = WrongHint { len: 2, hint: 4 }.collect();
+    assert_eq!(&*arr, &[1, 0]);
+    let arr: Array<usize> = WrongHint { len: 4, hint: 2 }.collect();
+    assert_eq!(&*arr, &[3, 2, 1, 0]);
+}
+
+
+#[test]
+fn from_slice() {
+    let source = ["a".to_string(), "b".to_string(), "c".to_string()];
+    let arr: Array<String> = source[1..].into();
+    assert_eq!(&*arr, &["b", "c"]);
+}
+
+
+#[test]
+fn from_vec_reuses_buffer() {
+    let source = vec![1, 2, 3];
+    let ptr = source.as_ptr();
+    let arr: Array<i32> = source.into();
+    assert_eq!(arr.as_ptr(), ptr);
+
+    let mut source = Vec::with_capacity(100);
+    source.extend_from_slice(&[1, 2, 3]);
+    let arr: Array<i32> = source.into();
+    assert_eq!(&*arr, &[1, 2, 3]);
+}
+
+
+#[test]
+fn hash() {
+    fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+    let arr: Array<i32> = vec![1, 2, 3].into();
+    assert_eq!(hash_of(&arr), hash_of(&[1, 2, 3][..]));
+    assert_ne!(hash_of(&arr), hash_of(&Array::from(vec![3, 2, 1])));
+}
+
+
 #[test]
 fn index() {
-    let mut arr: Array<i32> = vec![1,2,4].into();
+    let arr: Array<i32> = vec![1,2,4].into();
     assert_eq!(arr[1], 2);
 }
 
@@ -53,9 +263,50 @@ fn index_mut() {
 }
 
 
+#[test]
+fn index_mut_range() {
+    let mut arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    arr[..2].fill(0);
+    arr[3..].reverse();
+    arr[2..=2][0] = 7;
+    assert_eq!(arr, [0, 0, 7, 5, 4]);
+    arr[..].sort();
+    assert_eq!(arr, [0, 0, 4, 5, 7]);
+}
+
+
+#[test]
+#[should_panic(expected = "the len is 3 but the index is 3")]
+fn index_out_of_bounds() {
+    let arr: Array<i32> = vec![1, 2, 4].into();
+    let _ = arr[3];
+}
+
+
+#[test]
+fn index_range() {
+    let arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    assert_eq!(arr[2..5], [3, 4, 5]);
+    assert_eq!(arr[..2], [1, 2]);
+    assert_eq!(arr[3..], [4, 5]);
+    assert_eq!(arr[..=1], [1, 2]);
+    assert_eq!(arr[1..=3], [2, 3, 4]);
+    assert_eq!(arr[..], [1, 2, 3, 4, 5]);
+    assert!(arr[5..].is_empty());
+}
+
+
+#[test]
+#[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+fn index_range_out_of_bounds() {
+    let arr: Array<i32> = vec![1, 2, 3, 4, 5].into();
+    let _ = &arr[2..6];
+}
+
+
 #[test]
 fn into_iterator_ref() {
-    let mut arr: Array<i32> = vec![1,2,4].into();
+    let arr: Array<i32> = vec![1,2,4].into();
     for item in &arr {
         println!("{item}");
     }
@@ -74,10 +325,48 @@ fn into_iterator_ref_mut() {
 
 #[test]
 fn into_iterator() {
-    let mut arr: Array<i32> = vec![0,1,2].into();
-    let mut i = 0;
-    for x in arr {
-        assert_eq!(x, i);
-        i += 1;
+    let arr: Array<i32> = vec![0,1,2].into();
+    for (i, x) in arr.into_iter().enumerate() {
+        assert_eq!(x, i as i32);
     }
-}
\ No newline at end of file
+}
+
+
+#[test]
+fn ord() {
+    let a: Array<i32> = vec![1, 2, 3].into();
+    let b: Array<i32> = vec![1, 3].into();
+    let c: Array<i32> = vec![1, 2].into();
+    assert!(a < b);
+    assert!(c < a);
+    assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+
+    let floats: Array<f64> = vec![1.0, f64::NAN].into();
+    assert_eq!(floats.partial_cmp(&floats), None);
+}
+
+
+#[test]
+fn try_from_array() {
+    let arr: Array<String> = vec!["a".to_string(), "b".to_string()].into();
+    let arr = match <[String; 3]>::try_from(arr) {
+        Ok(_) => panic!("size is 2"),
+        Err(arr) => arr,
+    };
+    let array: [String; 2] = arr.try_into().ok().unwrap();
+    assert_eq!(array, ["a", "b"]);
+}
+
+
+#[test]
+fn try_from_boxed_array() {
+    let arr: Array<i32> = vec![1, 2, 3].into();
+    let ptr = arr.as_ptr();
+    let arr = match <Box<[i32; 2]>>::try_from(arr) {
+        Ok(_) => panic!("size is 3"),
+        Err(arr) => arr,
+    };
+    let boxed: Box<[i32; 3]> = arr.try_into().ok().unwrap();
+    assert_eq!(boxed.as_ptr(), ptr);
+    assert_eq!(*boxed, [1, 2, 3]);
+}
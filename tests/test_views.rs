@@ -0,0 +1,271 @@
+use runtime_sized_array::{Array, ArrayView};
+
+
+fn numbers(n: i32) -> Array<i32> {
+    Array::from_fn(n as usize, |i| i as i32)
+}
+
+
+#[test]
+fn view() {
+    let arr = numbers(4);
+    let view = arr.view();
+    assert_eq!(view.size(), 4);
+    assert_eq!((view.offset(), view.stride()), (0, 1));
+    assert_eq!(view, [0, 1, 2, 3]);
+    assert_eq!(view, *arr);
+}
+
+
+#[test]
+fn view_as_slice() {
+    let arr = numbers(5);
+    assert_eq!(arr.view().slice(1..4).as_slice(), Some(&[1, 2, 3][..]));
+    assert_eq!(arr.view().step_by(2).as_slice(), None);
+    assert_eq!(arr.view().rev().as_slice(), None);
+    // a single element is contiguous in any direction
+    assert_eq!(arr.view().rev().slice(..1).as_slice(), Some(&[4][..]));
+    assert_eq!(arr.view().slice(5..).as_slice(), Some(&[][..]));
+}
+
+
+#[test]
+fn view_debug() {
+    let arr = numbers(4);
+    assert_eq!(format!("{:?}", arr.view().rev()), "ArrayView([3, 2, 1, 0])");
+    assert_eq!(format!("{:?}", arr.view().step_by(2).iter()), "ViewIter([0, 2])");
+}
+
+
+#[test]
+fn view_index() {
+    let arr = numbers(6);
+    let view = arr.view().step_by(-2);
+    assert_eq!((view[0], view[1], view[2]), (5, 3, 1));
+    assert_eq!(view.try_get(2), Some(&1));
+    assert_eq!(view.try_get(3), None);
+}
+
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn view_index_out_of_bounds() {
+    let arr = numbers(4);
+    let _ = arr.view().step_by(2)[2];
+}
+
+
+#[test]
+fn view_iter() {
+    let arr = numbers(7);
+    let view = arr.view().slice(1..).step_by(3);
+    let mut iter = view.iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(view.into_iter().rev().collect::<Vec<_>>(), [&4, &1]);
+    assert_eq!((&view).into_iter().count(), 2);
+    assert_eq!(arr.view().rev().iter().nth(2), Some(&4));
+    assert_eq!(arr.view().rev().iter().nth_back(2), Some(&2));
+    assert_eq!(arr.view().step_by(-3).iter().last(), Some(&0));
+}
+
+
+#[test]
+fn view_negative_stride() {
+    let arr = numbers(10);
+    let view = arr.view().rev();
+    assert_eq!((view.offset(), view.stride()), (9, -1));
+    assert_eq!(view.slice(2..5), [7, 6, 5]);
+    assert_eq!(view.step_by(4), [9, 5, 1]);
+    // going backwards twice goes forwards
+    assert_eq!(view.step_by(-4), [0, 4, 8]);
+    assert_eq!(view.rev().slice(..3), [0, 1, 2]);
+    assert_eq!(view.slice(3..3).size(), 0);
+    assert_eq!(arr.view().slice(..0).rev().size(), 0);
+}
+
+
+#[test]
+fn view_overlapping() {
+    let arr = numbers(6);
+    let all = arr.view();
+    let even = all.step_by(2);
+    let reversed = all.rev();
+    // shared views may overlap and outlive each other
+    let copy = even;
+    assert_eq!(even, [0, 2, 4]);
+    assert_eq!(copy, even);
+    assert_eq!(reversed.step_by(2), [5, 3, 1]);
+    assert_eq!(arr[0], all[0]);
+}
+
+
+#[test]
+#[should_panic(expected = "range 2..5 is out of bounds of the size 3")]
+fn view_slice_out_of_bounds() {
+    let arr = numbers(6);
+    arr.view().step_by(2).slice(2..5);
+}
+
+
+#[test]
+fn view_split_at() {
+    let arr = numbers(5);
+    let (left, right) = arr.view().rev().split_at(2);
+    assert_eq!(left, [4, 3]);
+    assert_eq!(right, [2, 1, 0]);
+}
+
+
+#[test]
+#[should_panic(expected = "step must not be zero")]
+fn view_step_by_zero() {
+    let arr = numbers(3);
+    arr.view().step_by(0);
+}
+
+
+#[test]
+fn view_to_array() {
+    let arr: Array<String> = vec!["a".to_string(), "b".to_string(), "c".to_string()].into();
+    let copy = arr.view().rev().step_by(2).to_array();
+    assert_eq!(copy, ["c", "a"]);
+    assert_eq!(ArrayView::to_array(&arr.view().slice(1..1)).size(), 0);
+}
+
+
+#[test]
+fn view_zero_sized() {
+    let arr: Array<()> = Array::new(7).unwrap();
+    assert_eq!(arr.view().step_by(-3).iter().count(), 3);
+    assert_eq!(arr.view().rev().slice(2..).size(), 5);
+}
+
+
+#[test]
+fn view_mut_as_slice() {
+    let mut arr = numbers(4);
+    let (mut left, right) = arr.split_at_mut_array(1);
+    left.as_mut_slice().unwrap()[0] = 9;
+    assert_eq!(left.as_slice(), Some(&[9][..]));
+    assert_eq!(right.into_slice(), Some(&mut [1, 2, 3][..]));
+    assert_eq!(arr.view_mut().rev().into_slice(), None);
+    assert_eq!(arr, [9, 1, 2, 3]);
+}
+
+
+#[test]
+fn view_mut_debug() {
+    let mut arr = numbers(3);
+    let (left, _) = arr.split_at_mut_array(2);
+    assert_eq!(format!("{left:?}"), "ArrayViewMut([0, 1])");
+    let mut view = arr.view_mut().rev();
+    let mut iter = view.iter_mut();
+    iter.next();
+    assert_eq!(format!("{iter:?}"), "ViewIterMut([1, 0])");
+}
+
+
+#[test]
+fn view_mut_index() {
+    let mut arr = numbers(4);
+    let (_, mut right) = arr.split_at_mut_array(2);
+    assert_eq!(right[1], 3);
+    right[0] = 7;
+    assert_eq!(right.try_get(1), Some(&3));
+    assert_eq!(right.try_get(2), None);
+    *right.try_get_mut(1).unwrap() = 8;
+    assert_eq!(right.try_get_mut(2), None);
+    assert_eq!(arr, [0, 1, 7, 8]);
+}
+
+
+#[test]
+#[should_panic(expected = "the len is 2 but the index is 2")]
+fn view_mut_index_out_of_bounds() {
+    let mut arr = numbers(4);
+    let (left, _) = arr.split_at_mut_array(2);
+    let _ = left[2];
+}
+
+
+#[test]
+fn view_mut_iter() {
+    let mut arr = numbers(5);
+    let (mut left, right) = arr.split_at_mut_array(2);
+    assert_eq!(left.iter().sum::<i32>(), 1);
+    left.iter_mut().for_each(|x| *x *= 10);
+    for x in right {
+        *x = -*x;
+    }
+    assert_eq!(arr, [0, 10, -2, -3, -4]);
+
+    let mut view = arr.view_mut().step_by(-2);
+    for x in &mut view {
+        *x += 100;
+    }
+    assert_eq!((&view).into_iter().count(), 3);
+    let mut iter = view.iter_mut();
+    *iter.next_back().unwrap() = 0;
+    *iter.nth(1).unwrap() = 1;
+    assert_eq!(iter.len(), 0);
+    assert_eq!(arr, [0, 10, 1, -3, 96]);
+}
+
+
+#[test]
+fn view_mut_negative_stride() {
+    let mut arr = numbers(8);
+    let view = arr.view_mut().rev().step_by(3);
+    assert_eq!((view.offset(), view.stride(), view.size()), (7, -3, 3));
+    for x in view {
+        *x = 0;
+    }
+    assert_eq!(arr, [0, 0, 2, 3, 0, 5, 6, 0]);
+
+    let mut view = arr.view_mut().slice(2..6).rev();
+    view[0] = 50;
+    assert_eq!(view, [50, 0, 3, 2]);
+    assert_eq!(view.to_array(), [50, 0, 3, 2]);
+}
+
+
+#[test]
+fn view_mut_reborrow() {
+    let mut arr = numbers(6);
+    let mut view = arr.view_mut();
+    view.reborrow().step_by(2).iter_mut().for_each(|x| *x = -1);
+    view.reborrow().rev().slice(..2).iter_mut().for_each(|x| *x *= 10);
+    assert_eq!(view.view().step_by(5), [-1, 50]);
+    assert_eq!(arr, [-1, 1, -1, 3, -10, 50]);
+}
+
+
+#[test]
+fn view_mut_split_at() {
+    let mut arr = numbers(6);
+    let (left, right) = arr.split_at_mut_array(3);
+    let (mut a, mut b) = left.split_at(1);
+    let (mut c, mut d) = right.rev().split_at(2);
+    a[0] = 10;
+    b[1] = 20;
+    c[0] = 30;
+    d[0] = 40;
+    assert_eq!(arr, [10, 1, 20, 40, 4, 30]);
+}
+
+
+#[test]
+fn view_mut_split_strided() {
+    // the halves of a strided view interleave with the skipped elements, but not with each other
+    let mut arr = numbers(8);
+    let (mut first, mut second) = arr.view_mut().step_by(2).split_at(2);
+    std::mem::swap(&mut first[1], &mut second[1]);
+    assert_eq!(first, [0, 6]);
+    assert_eq!(second, [4, 2]);
+    assert_eq!(arr, [0, 1, 6, 3, 4, 5, 2, 7]);
+}
@@ -0,0 +1,112 @@
+use std::cell::Cell;
+use runtime_sized_array::Array;
+
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Zero-sized type, which counts its drops.
+#[derive(Clone, Debug, PartialEq)]
+struct Marker;
+
+impl Drop for Marker {
+    fn drop(&mut self) {
+        DROPS.with(|c| c.set(c.get() + 1));
+    }
+}
+
+fn drops() -> usize {
+    DROPS.with(|c| c.get())
+}
+
+fn markers(n: usize) -> Array<Marker> {
+    let mut arr = Array::new_uninit(n).unwrap();
+    for i in 0..n {
+        arr.write(i, Marker);
+    }
+    unsafe { arr.assume_init() }
+}
+
+
+#[test]
+fn zst_new() {
+    let arr: Array<()> = Array::new(5).unwrap();
+    assert_eq!(arr.size(), 5);
+    assert_eq!(arr[4], ());
+}
+
+
+#[test]
+fn zst_iter() {
+    let mut arr = markers(3);
+    assert_eq!(arr.iter().count(), 3);
+    assert_eq!(arr.iter_mut().count(), 3);
+    assert_eq!((&arr).into_iter().count(), 3);
+}
+
+
+#[test]
+fn zst_into_iter() {
+    let arr: Array<()> = Array::new(4).unwrap();
+    let mut count = 0;
+    for unit in arr {
+        assert_eq!(unit, ());
+        count += 1;
+    }
+    assert_eq!(count, 4);
+
+    let before = drops();
+    let mut iter = markers(4).into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(iter.len(), 2);
+    drop(iter);
+    assert_eq!(drops(), before + 4);
+}
+
+
+#[test]
+fn zst_drop() {
+    let before = drops();
+    drop(markers(6));
+    assert_eq!(drops(), before + 6);
+}
+
+
+#[test]
+fn zst_into_vec() {
+    let before = drops();
+    let vec = markers(3).into_vec();
+    assert_eq!(vec.len(), 3);
+    assert_eq!(drops(), before);
+    drop(vec);
+    assert_eq!(drops(), before + 3);
+}
+
+
+#[test]
+fn zero_length_new() {
+    let arr: Array<i32> = Array::new(0).unwrap();
+    assert_eq!(arr.size(), 0);
+    assert_eq!(arr.try_get(0), None);
+    let arr = Array::<String>::new_uninit(0).unwrap();
+    assert_eq!(unsafe { arr.assume_init() }.size(), 0);
+}
+
+
+#[test]
+fn zero_length_iter() {
+    let mut arr: Array<String> = Vec::new().into();
+    assert_eq!(arr.iter().next(), None);
+    assert_eq!(arr.iter_mut().next(), None);
+    assert_eq!(arr.into_iter().next(), None);
+}
+
+
+#[test]
+fn zero_length_into_vec() {
+    let arr: Array<String> = Vec::new().into();
+    assert_eq!(arr.clone().size(), 0);
+    assert!(arr.into_vec().is_empty());
+}